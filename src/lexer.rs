@@ -15,7 +15,7 @@ use token_identifier::token_identifier;
 
 const NUMBERS: &str = "0123456789";
 const OPERATORS: &str = "+-*/%=&|<>!^~?";
-const PUNCTUATION: &str = "(){}[],.;:";
+const PUNCTUATION: &str = "(){}[],.;:@";
 
 fn token_error(token: &util::Token<TokenType>) -> ErrorTypes {
   let (data_line, token_value) = split_meta(&token.meta);
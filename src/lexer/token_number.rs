@@ -27,6 +27,10 @@ fn number_literal(
     if !is_number(c, use_dot) {
       break;
     }
+    // 1..10 is a range, not a decimal
+    if c == '.' && line.chars().nth(i + 1) == Some('.') {
+      break;
+    }
     if c == '.' {
       use_dot = true;
     }
@@ -28,8 +28,21 @@ pub enum KeywordsType {
   Await,
   Async,
   Console,
+  Yield,
+  True,
+  False,
+  Null,
+  Undefined,
+  Is,
+  In,
+  Delete,
+  Enum,
+  Interface,
+  Implements,
+  Using,
+  Defer,
 }
-type KeywordsList = [KeywordsType; 28];
+type KeywordsList = [KeywordsType; 41];
 const KEYWORDS: KeywordsList = [
   KeywordsType::None,
   KeywordsType::Define,
@@ -58,7 +71,20 @@ const KEYWORDS: KeywordsList = [
   KeywordsType::Lazy,
   KeywordsType::Await,
   KeywordsType::Async,
-  KeywordsType::Console
+  KeywordsType::Console,
+  KeywordsType::Yield,
+  KeywordsType::True,
+  KeywordsType::False,
+  KeywordsType::Null,
+  KeywordsType::Undefined,
+  KeywordsType::Is,
+  KeywordsType::In,
+  KeywordsType::Delete,
+  KeywordsType::Enum,
+  KeywordsType::Interface,
+  KeywordsType::Implements,
+  KeywordsType::Using,
+  KeywordsType::Defer,
 ];
 impl KeywordsType {
   pub const fn iter() -> KeywordsList {
@@ -94,6 +120,19 @@ impl KeywordsType {
       KeywordsType::Await => "espera",
       KeywordsType::Async => "asinc",
       KeywordsType::Console => "csl",
+      KeywordsType::Yield => "produce",
+      KeywordsType::True => "cierto",
+      KeywordsType::False => "falso",
+      KeywordsType::Null => "nulo",
+      KeywordsType::Undefined => "indefinido",
+      KeywordsType::Is => "es",
+      KeywordsType::In => "en",
+      KeywordsType::Delete => "borra",
+      KeywordsType::Enum => "enumera",
+      KeywordsType::Interface => "interfaz",
+      KeywordsType::Implements => "implementa",
+      KeywordsType::Using => "usando",
+      KeywordsType::Defer => "difiere",
     }
   }
   pub fn to_string(&self) -> String {
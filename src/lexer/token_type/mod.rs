@@ -13,7 +13,7 @@ pub enum TokenType {
   Number,                       // 0b1010, 0x1A, 0o12, 0$17$e, etc
   String,                       // "hello {variable}"
   Operator(OperatorType),       // + - * / % & | ^ ~ ! = < >
-  Punctuation(PunctuationType), // ( ) { } [ ] , ; : .
+  Punctuation(PunctuationType), // ( ) { } [ ] , ; : . @
   Keyword(KeywordsType),
   Error,
   None,
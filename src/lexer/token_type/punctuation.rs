@@ -1,6 +1,6 @@
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum PunctuationType {
-  // ( ) { } [ ] , ; : .
+  // ( ) { } [ ] , ; : . @
   None,
   CircularBracketOpen,
   CircularBracketClose,
@@ -12,6 +12,7 @@ pub enum PunctuationType {
   SemiColon,
   DoubleDot,
   Dot,
+  At,
 }
 impl PunctuationType {
   pub fn from(c: char) -> Self {
@@ -26,6 +27,7 @@ impl PunctuationType {
       ';' => Self::SemiColon,
       ':' => Self::DoubleDot,
       '.' => Self::Dot,
+      '@' => Self::At,
       _ => Self::None,
     }
   }
@@ -10,11 +10,13 @@ use crate::{
 };
 
 const MISSING_TOKEN: &str = "\x1b[81mToken desaparecido\x1b[0m";
-
-struct SemiToken {
-  value: String,
-  location: util::Location,
-}
+const CONSTRUCTOR: &str = "constructor";
+const GETTER: &str = "obtener";
+const SETTER: &str = "establecer";
+const OPERATOR: &str = "operador";
+/// tipo de valor, the words are only reserved together
+const TYPEOF: &str = "tipo";
+const TYPEOF_OF: &str = "de";
 
 pub fn node_error(error: &ast::NodeError) -> internal::ErrorTypes {
   let line: usize;
@@ -69,6 +71,22 @@ pub struct Parser {
   tokens: Vec<Token<TokenType>>,
   index: usize,
   file_name: String,
+  /// labels of the enclosing loops, the last one is the innermost
+  labels: Vec<String>,
+  /// inside the body of a generator function, changed at each function or class body
+  in_generator: bool,
+  /// context flag of the current statement, used by the expressions
+  in_async: bool,
+  /// parsing the right side of an operator, an assignment can't start there
+  in_operand: bool,
+  /// index of the first token of the current operation, where an assignment target starts
+  operation_start: usize,
+  /// names declared in the enclosing scopes, `true` for the constants
+  bindings: Vec<Vec<(String, bool)>>,
+  /// inside the body of a `nombre X { ... }`
+  in_namespace: bool,
+  /// allows 'espera' outside of the async functions
+  top_level_await: bool,
 }
 impl Parser {
   pub fn new(source: String, file_name: &str) -> Parser {
@@ -78,8 +96,65 @@ impl Parser {
       tokens,
       index: 0,
       file_name: file_name.to_string(),
+      labels: Vec::new(),
+      in_generator: false,
+      in_async: false,
+      in_operand: false,
+      in_namespace: false,
+      operation_start: 0,
+      bindings: Vec::new(),
+      top_level_await: true,
     }
   }
+  pub fn set_top_level_await(&mut self, allow: bool) {
+    self.top_level_await = allow;
+  }
+  /// parses an expression written inside another token (the string interpolations),
+  /// the tokens are moved to the position of the fragment in the source
+  fn parse_fragment(
+    &self,
+    fragment: &str,
+    line: usize,
+    column: usize,
+  ) -> Result<ast::Node, NodeError> {
+    let mut parser = Parser::new(fragment.to_string(), &self.file_name);
+    parser.source = self.source.clone();
+    // the fragment is checked in the context of the string
+    parser.labels = self.labels.clone();
+    parser.in_generator = self.in_generator;
+    parser.in_async = self.in_async;
+    parser.bindings = self.bindings.clone();
+    parser.top_level_await = self.top_level_await;
+    let source_line = self.source.lines().nth(line).unwrap_or("");
+    if parser.tokens.is_empty() {
+      return Err(ast::NodeError {
+        message: "No se pudo analizar la interpolacion".to_string(),
+        location: util::Location {
+          start: util::Position { line, column },
+          end: util::Position { line, column },
+          length: 0,
+          file_name: self.file_name.clone(),
+        },
+        meta: format!("{}\0{}", source_line, fragment),
+      });
+    }
+    for token in parser.tokens.iter_mut() {
+      token.location.start.line += line;
+      token.location.end.line += line;
+      token.location.start.column += column;
+      token.location.end.column += column;
+    }
+    let node = parser.parse_expr()?;
+    let token = parser.at();
+    if token.token_type != TokenType::EOF {
+      return Err(ast::NodeError {
+        message: "Token inesperado en la interpolacion".to_string(),
+        location: token.location,
+        meta: format!("{}\0{}", source_line, token.value),
+      });
+    }
+    Ok(node)
+  }
   fn is_eof(&mut self) -> bool {
     self.index >= self.tokens.len()
   }
@@ -189,6 +264,47 @@ impl Parser {
   fn check_token(&mut self, token_type: TokenType) -> bool {
     self.at().token_type == token_type
   }
+  /// the current and the next token are the given pair and are written together
+  fn check_join_tokens(&self, first: TokenType, second: TokenType) -> bool {
+    let current = self.at();
+    let next = self.next();
+    current.token_type == first
+      && next.token_type == second
+      && current.location.start.line == next.location.start.line
+      && current.location.end.column == next.location.start.column
+  }
+  fn check_update_operator(&self) -> Option<ast::NodeOperator> {
+    let plus = TokenType::Operator(OperatorType::Plus);
+    let minus = TokenType::Operator(OperatorType::Minus);
+    if self.check_join_tokens(plus, plus) {
+      Some(ast::NodeOperator::Increment)
+    } else if self.check_join_tokens(minus, minus) {
+      Some(ast::NodeOperator::Decrement)
+    } else {
+      None
+    }
+  }
+  fn check_pipeline(&self) -> bool {
+    // |>
+    self.check_join_tokens(
+      TokenType::Operator(OperatorType::Or),
+      TokenType::Operator(OperatorType::GreaterThan),
+    )
+  }
+  fn check_range(&self) -> bool {
+    // ..
+    self.check_join_tokens(
+      TokenType::Punctuation(PunctuationType::Dot),
+      TokenType::Punctuation(PunctuationType::Dot),
+    )
+  }
+  fn check_optional_chain(&self) -> bool {
+    // ?.
+    self.check_join_tokens(
+      TokenType::Operator(OperatorType::QuestionMark),
+      TokenType::Punctuation(PunctuationType::Dot),
+    )
+  }
   fn check_in_tokens(&mut self, token_types: Vec<TokenType>) -> bool {
     for token_type in token_types {
       if self.check_token(token_type) {
@@ -236,7 +352,7 @@ impl Parser {
     }
   }
   pub fn produce_ast(&mut self) -> Result<ast::Node, NodeError> {
-    let body = self.parse_block(true, false, false, true, TokenType::EOF)?;
+    let body = self.parse_block(true, false, false, self.top_level_await, TokenType::EOF)?;
     let location = body.clone().location;
     ast::Node::Program(ast::NodeProgram {
       body,
@@ -251,6 +367,18 @@ impl Parser {
     is_function: bool,
     is_loop: bool,
     is_async: bool,
+  ) -> Option<Result<ast::Node, NodeError>> {
+    let in_async = std::mem::replace(&mut self.in_async, is_async);
+    let stmt = self.parse_stmt_value(is_global_scope, is_function, is_loop, is_async);
+    self.in_async = in_async;
+    stmt
+  }
+  fn parse_stmt_value(
+    &mut self,
+    is_global_scope: bool,
+    is_function: bool,
+    is_loop: bool,
+    is_async: bool,
   ) -> Option<Result<ast::Node, NodeError>> {
     let token = self.at();
     match token.token_type {
@@ -273,6 +401,9 @@ impl Parser {
         | KeywordsType::Function
         | KeywordsType::Try
         | KeywordsType::Class
+        | KeywordsType::Enum
+        | KeywordsType::Interface
+        | KeywordsType::Using
         | KeywordsType::Para
         | KeywordsType::Async => Some(self.parse_keyword_value(is_function, is_loop, is_async)),
         KeywordsType::Console => {
@@ -296,39 +427,229 @@ impl Parser {
         }
         KeywordsType::Export => Some(self.parse_export_decl(is_global_scope)),
         KeywordsType::Import => Some(self.parse_import_decl(is_global_scope)),
+        KeywordsType::Name => Some(self.parse_name_decl(is_global_scope)),
         KeywordsType::Throw => Some(self.parse_throw_decl()),
-        KeywordsType::Await => {
-          self.eat(); // await
-          if !is_async {
-            return Some(Err(ast::NodeError {
-              message: format!(
-                "La palabra clave '{}' solo se puede utilizar en un contexto asíncrono",
-                KeywordsType::Await.as_str()
-              ),
-              location: token.location,
-              meta: token.meta,
-            }));
-          }
-          Some(
-            ast::Node::Await(ast::NodeExpressionMedicator {
-              expression: match self.parse_stmt_expr() {
-                Err(e) => return Some(Err(e)),
-                Ok(expr) => expr.to_box(),
-              },
-              location: token.location,
-              file: token.meta,
-            })
-            .into(),
-          )
-        }
+        KeywordsType::Defer => Some(self.parse_defer_decl()),
+        KeywordsType::Yield => Some(self.parse_stmt_expr()),
+        KeywordsType::Await
+        | KeywordsType::True
+        | KeywordsType::False
+        | KeywordsType::Null
+        | KeywordsType::Undefined
+        | KeywordsType::Delete => Some(self.parse_stmt_expr()),
         _ => {
           self.eat();
           None
         }
       },
+      TokenType::Punctuation(PunctuationType::At) => {
+        Some(self.parse_attributed_decl(is_global_scope, is_function, is_loop, is_async))
+      }
+      TokenType::Identifier
+        if self.next().token_type == TokenType::Punctuation(PunctuationType::DoubleDot)
+          && matches!(
+            self.look(2).token_type,
+            TokenType::Keyword(KeywordsType::Para | KeywordsType::While | KeywordsType::Do)
+          ) =>
+      {
+        Some(self.parse_labeled_decl(is_function, is_loop, is_async))
+      }
       _ => Some(self.parse_stmt_expr()),
     }
   }
+  fn parse_attributes(&mut self) -> Result<List<ast::NodeAttribute>, ast::NodeError> {
+    let mut attributes = List::new();
+    while self.match_token(TokenType::Punctuation(PunctuationType::At)) {
+      let name = self.expect(TokenType::Identifier, "Se esperaba el nombre del atributo");
+      if name.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: name.value.clone(),
+          location: name.location,
+          meta: name.meta,
+        });
+      }
+      let mut arguments = List::new();
+      if self.match_token(TokenType::Punctuation(PunctuationType::CircularBracketOpen)) {
+        while !(self.is_eof()
+          || self.match_token(TokenType::Punctuation(
+            PunctuationType::CircularBracketClose,
+          )))
+        {
+          arguments.push(self.parse_expr()?);
+          if self.check_token(TokenType::Punctuation(
+            PunctuationType::CircularBracketClose,
+          )) || self.match_token(TokenType::Punctuation(PunctuationType::Comma))
+          {
+            continue;
+          }
+          let comma = self.at();
+          let line = self.source.lines().nth(comma.location.start.line).unwrap();
+          return Err(ast::NodeError {
+            message: "Se esperaba una coma (atributo)".to_string(),
+            location: comma.location,
+            meta: format!("{}\0{}", line, comma.value),
+          });
+        }
+      }
+      attributes.push(ast::NodeAttribute {
+        name: name.value,
+        arguments,
+        location: name.location,
+        file: name.meta,
+      });
+    }
+    Ok(attributes)
+  }
+  fn parse_attributed_decl(
+    &mut self,
+    is_global_scope: bool,
+    is_function: bool,
+    is_loop: bool,
+    is_async: bool,
+  ) -> Result<ast::Node, NodeError> {
+    let token = self.at(); // @
+    let attributes = self.parse_attributes()?;
+    let mut node = match self.at().token_type {
+      TokenType::Keyword(KeywordsType::Export) => self.parse_export_decl(is_global_scope)?,
+      TokenType::Keyword(KeywordsType::Function | KeywordsType::Async | KeywordsType::Class) => {
+        self.parse_keyword_value(is_function, is_loop, is_async)?
+      }
+      _ => ast::Node::None,
+    };
+    let target = match node {
+      ast::Node::Export(ref mut export) => export.value.as_mut(),
+      ref mut node => node,
+    };
+    match target {
+      ast::Node::Function(function) => function.attributes = attributes,
+      ast::Node::Class(class) => class.attributes = attributes,
+      _ => {
+        let line = self.source.lines().nth(token.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Los atributos solo se pueden usar en funciones, clases y sus miembros"
+            .to_string(),
+          location: token.location,
+          meta: format!("{}\0{}", line, token.value),
+        });
+      }
+    }
+    Ok(node)
+  }
+  fn parse_labeled_decl(
+    &mut self,
+    is_function: bool,
+    is_loop: bool,
+    is_async: bool,
+  ) -> Result<ast::Node, NodeError> {
+    let label = self.eat(); // etiqueta
+    self.eat(); // :
+    if self.labels.contains(&label.value) {
+      let line = self.source.lines().nth(label.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: format!("La etiqueta '{}' ya esta en uso", label.value),
+        location: label.location,
+        meta: format!("{}\0{}", line, label.value),
+      });
+    }
+    self.labels.push(label.value.clone());
+    let node = self.parse_keyword_value(is_function, is_loop, is_async);
+    self.labels.pop();
+    let mut node = node?;
+    match node {
+      ast::Node::For(ref mut node) => node.label = Some(label.value),
+      ast::Node::ForEach(ref mut node) => node.label = Some(label.value),
+      ast::Node::While(ref mut node) | ast::Node::DoWhile(ref mut node) => {
+        node.label = Some(label.value)
+      }
+      _ => {}
+    }
+    Ok(node)
+  }
+  fn parse_using_decl(
+    &mut self,
+    is_function: bool,
+    is_loop: bool,
+    is_async: bool,
+  ) -> Result<ast::Node, NodeError> {
+    let token = self.eat(); // usando
+    let open_paren = self.expect(
+      TokenType::Punctuation(PunctuationType::CircularBracketOpen),
+      "",
+    );
+    if open_paren.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: "Se esperaba un paréntesis de apertura".to_string(),
+        location: open_paren.location,
+        meta: open_paren.meta,
+      });
+    }
+    // the declared resources belong to the block
+    self.bindings.push(Vec::new());
+    let resource = if self.check_in_tokens(vec![
+      TokenType::Keyword(KeywordsType::Define),
+      TokenType::Keyword(KeywordsType::Constant),
+    ]) {
+      let declaration = self.parse_declarators()?;
+      for declarator in declaration.declarators.clone() {
+        if declarator.value.is_none() {
+          let line = self
+            .source
+            .lines()
+            .nth(declarator.location.start.line)
+            .unwrap();
+          return Err(ast::NodeError {
+            message: "Un recurso debe tener un valor".to_string(),
+            location: declarator.location,
+            meta: format!("{}\0{}", line, declarator.name),
+          });
+        }
+      }
+      ast::Node::VarDecl(declaration)
+    } else {
+      self.parse_expr()?
+    };
+    let close_paren = self.expect(
+      TokenType::Punctuation(PunctuationType::CircularBracketClose),
+      "",
+    );
+    if close_paren.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: "Se esperaba un paréntesis de cierre".to_string(),
+        location: close_paren.location,
+        meta: close_paren.meta,
+      });
+    }
+    let body = self.parse_block_expr(is_function, is_loop, is_async);
+    self.bindings.pop();
+    ast::Node::Using(ast::NodeUsing {
+      resource: resource.to_box(),
+      body: body?,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_defer_decl(&mut self) -> Result<ast::Node, NodeError> {
+    let token = self.eat(); // difiere
+    let expr = self.parse_expr()?;
+    let semicolon = self.expect(TokenType::Punctuation(PunctuationType::SemiColon), "");
+    if semicolon.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: format!(
+          "Se esperaba un punto y coma ({})",
+          KeywordsType::Defer.to_string()
+        ),
+        location: semicolon.location,
+        meta: semicolon.meta,
+      });
+    }
+    ast::Node::Defer(ast::NodeValue {
+      value: Box::new(expr),
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
   fn parse_throw_decl(&mut self) -> Result<ast::Node, NodeError> {
     let token = self.eat(); // lanzar
     let expr = self.parse_expr()?;
@@ -389,10 +710,14 @@ impl Parser {
         meta: semicolon.meta,
       });
     }
-    if !is_global_scope {
+    if !is_global_scope || self.in_namespace {
       let line = self.source.lines().nth(token.location.start.line).unwrap();
       return Err(ast::NodeError {
-        message: "No se puede importar fuera del ámbito global".to_string(),
+        message: if self.in_namespace {
+          "No se puede importar dentro de un nombre".to_string()
+        } else {
+          "No se puede importar fuera del ámbito global".to_string()
+        },
         location: token.location,
         meta: format!("{}\0{}", line, token.value),
       });
@@ -433,10 +758,14 @@ impl Parser {
     let token = self.at();
     match token.token_type {
       TokenType::Keyword(KeywordsType::Define | KeywordsType::Constant) => self.parse_var_decl(),
-      TokenType::Keyword(KeywordsType::Function | KeywordsType::Class) => {
-        self.parse_keyword_value(false, false, false)
-      }
-      TokenType::Keyword(KeywordsType::Name) => self.parse_name_decl(),
+      TokenType::Keyword(
+        KeywordsType::Function
+        | KeywordsType::Class
+        | KeywordsType::Enum
+        | KeywordsType::Interface
+        | KeywordsType::Async,
+      ) => self.parse_keyword_value(false, false, false),
+      TokenType::Keyword(KeywordsType::Name) => self.parse_name_decl(true),
       _ => {
         self.eat();
         let line = self.source.lines().nth(token.location.start.line).unwrap();
@@ -453,7 +782,182 @@ impl Parser {
       }
     }
   }
-  fn parse_name_decl(&mut self) -> Result<ast::Node, NodeError> {
+  fn parse_interface_decl(&mut self) -> Result<ast::Node, NodeError> {
+    let token = self.eat(); // interfaz
+    let name = self.expect(TokenType::Identifier, "Se esperaba un identificador");
+    if name.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: name.value.clone(),
+        location: name.location,
+        meta: name.meta,
+      });
+    }
+    let open_brace = self.expect(
+      TokenType::Punctuation(PunctuationType::RegularBracketOpen),
+      "",
+    );
+    if open_brace.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: "Se esperaba un corchete de apertura".to_string(),
+        location: open_brace.location,
+        meta: open_brace.meta,
+      });
+    }
+    let mut methods = List::new();
+    let mut names: Vec<String> = Vec::new();
+    while !(self.is_eof()
+      || self.match_token(TokenType::Punctuation(PunctuationType::RegularBracketClose)))
+    {
+      let is_async = self.match_token(TokenType::Keyword(KeywordsType::Async));
+      let method = self.expect(TokenType::Identifier, "Se esperaba el nombre del metodo");
+      if method.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: method.value,
+          location: method.location,
+          meta: method.meta,
+        });
+      }
+      if names.contains(&method.value) {
+        let line = self.source.lines().nth(method.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: format!(
+            "El metodo '{}' ya esta definido en la interfaz",
+            method.value
+          ),
+          location: method.location,
+          meta: format!("{}\0{}", line, method.value),
+        });
+      }
+      names.push(method.value.clone());
+      let params = self.parse_arguments_expr()?;
+      let return_type = self.parse_type_annotation()?;
+      // a signature ends with a semicolon, a default method has a body
+      let body = if self.match_token(TokenType::Punctuation(PunctuationType::SemiColon)) {
+        None
+      } else if self.check_token(TokenType::Punctuation(PunctuationType::RegularBracketOpen)) {
+        Some(self.parse_function_body(true, &params, is_async, false)?)
+      } else {
+        let token = self.at();
+        let line = self.source.lines().nth(token.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Se esperaba ';' o un cuerpo".to_string(),
+          location: token.location,
+          meta: format!("{}\0{}", line, token.value),
+        });
+      };
+      methods.push(ast::NodeInterfaceMethod {
+        name: method.value,
+        is_async,
+        params,
+        return_type,
+        body,
+        location: method.location,
+        file: method.meta,
+      });
+    }
+    ast::Node::Interface(ast::NodeInterface {
+      name: name.value,
+      methods,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_enum_decl(&mut self) -> Result<ast::Node, NodeError> {
+    let token = self.eat(); // enumera
+    let name = self.expect(TokenType::Identifier, "Se esperaba un identificador");
+    if name.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: name.value.clone(),
+        location: name.location,
+        meta: name.meta,
+      });
+    }
+    let open_brace = self.expect(
+      TokenType::Punctuation(PunctuationType::RegularBracketOpen),
+      "",
+    );
+    if open_brace.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: "Se esperaba un corchete de apertura".to_string(),
+        location: open_brace.location,
+        meta: open_brace.meta,
+      });
+    }
+    let mut variants = List::new();
+    let mut names: Vec<String> = Vec::new();
+    while !(self.is_eof()
+      || self.match_token(TokenType::Punctuation(PunctuationType::RegularBracketClose)))
+    {
+      let variant = self.expect(
+        TokenType::Identifier,
+        "Se esperaba el nombre de la variante",
+      );
+      if variant.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: variant.value,
+          location: variant.location,
+          meta: variant.meta,
+        });
+      }
+      if names.contains(&variant.value) {
+        let line = self
+          .source
+          .lines()
+          .nth(variant.location.start.line)
+          .unwrap();
+        return Err(ast::NodeError {
+          message: format!(
+            "La variante '{}' ya esta definida en el enum",
+            variant.value
+          ),
+          location: variant.location,
+          meta: format!("{}\0{}", line, variant.value),
+        });
+      }
+      names.push(variant.value.clone());
+      // Punto(x, y)
+      let data = if self.check_token(TokenType::Punctuation(PunctuationType::CircularBracketOpen)) {
+        self.parse_arguments_expr()?
+      } else {
+        List::new()
+      };
+      // Verde = 5
+      let value = if self.match_token(TokenType::Operator(OperatorType::Equals)) {
+        Some(self.parse_expr()?.to_box())
+      } else {
+        None
+      };
+      variants.push(ast::NodeEnumVariant {
+        name: variant.value,
+        value,
+        data,
+        location: variant.location,
+        file: variant.meta,
+      });
+      if self.match_token(TokenType::Punctuation(PunctuationType::Comma)) {
+        continue;
+      }
+      if self.match_token(TokenType::Punctuation(PunctuationType::RegularBracketClose)) {
+        break;
+      }
+      let comma = self.at();
+      let line = self.source.lines().nth(comma.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: "Se esperaba una coma (enum)".to_string(),
+        location: comma.location,
+        meta: format!("{}\0{}", line, comma.value),
+      });
+    }
+    ast::Node::Enum(ast::NodeEnum {
+      name: name.value,
+      variants,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_name_decl(&mut self, is_global_scope: bool) -> Result<ast::Node, NodeError> {
     let token = self.eat(); // nombre
     let name = self.expect(TokenType::Identifier, "Se esperaba un identificador");
     if name.token_type == TokenType::Error {
@@ -463,6 +967,34 @@ impl Parser {
         meta: name.meta,
       });
     }
+    if !is_global_scope {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: "No se puede declarar un nombre fuera del ámbito global".to_string(),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    }
+    // nombre X { ... }
+    if self.match_token(TokenType::Punctuation(PunctuationType::RegularBracketOpen)) {
+      // the members are global declarations, but the imports belong to the file
+      let in_namespace = std::mem::replace(&mut self.in_namespace, true);
+      let body = self.parse_block(
+        true,
+        false,
+        false,
+        self.top_level_await,
+        TokenType::Punctuation(PunctuationType::RegularBracketClose),
+      );
+      self.in_namespace = in_namespace;
+      return ast::Node::Namespace(ast::NodeNamespace {
+        name: name.value,
+        body: body?,
+        location: token.location,
+        file: token.meta,
+      })
+      .into();
+    }
     let semicolon = self.expect(TokenType::Punctuation(PunctuationType::SemiColon), "");
     if semicolon.token_type == TokenType::Error {
       return Err(ast::NodeError {
@@ -485,13 +1017,62 @@ impl Parser {
     &mut self,
     is_static: bool,
     is_public: bool,
+    is_const: bool,
   ) -> Result<ast::NodeClassProperty, ast::NodeError> {
+    let start = self.at();
+    // est { ... }
+    if is_static && self.check_token(TokenType::Punctuation(PunctuationType::RegularBracketOpen)) {
+      if is_public || is_const {
+        let line = self.source.lines().nth(start.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Un bloque estatico no admite modificadores".to_string(),
+          location: start.location,
+          meta: format!("{}\0{}", line, start.value),
+        });
+      }
+      let body = self.parse_function_body(false, &List::new(), false, false)?;
+      return Ok(ast::NodeClassProperty {
+        name: "".to_string(),
+        kind: ast::NodeClassPropertyKind::StaticBlock(body),
+        attributes: List::new(),
+        type_annotation: None,
+        is_static,
+        is_public,
+        is_const,
+        is_async: false,
+        location: start.location,
+        file: start.meta,
+      });
+    }
     let is_async = if self.at().token_type == TokenType::Keyword(KeywordsType::Async) {
       self.eat();
       true
     } else {
       false
     };
+    // *nombre() {}
+    let is_generator = self.match_token(TokenType::Operator(OperatorType::Star));
+    // operador + (otro) {}
+    if self.check_class_operator() {
+      if is_static || is_const || is_async || is_generator {
+        let line = self.source.lines().nth(start.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Un operador no admite los modificadores est, const, asinc ni *".to_string(),
+          location: start.location,
+          meta: format!("{}\0{}", line, start.value),
+        });
+      }
+      return self.parse_class_operator(is_public);
+    }
+    // obtener nombre() {} and establecer nombre(valor) {}
+    let accessor = if self.check_token(TokenType::Identifier)
+      && (self.at().value == GETTER || self.at().value == SETTER)
+      && self.next().token_type == TokenType::Identifier
+    {
+      Some(self.eat())
+    } else {
+      None
+    };
     let name = self.expect(TokenType::Identifier, "Se esperaba un identificador");
     if name.token_type == TokenType::Error {
       return Err(ast::NodeError {
@@ -509,39 +1090,124 @@ impl Parser {
         meta: token.meta,
       });
     }
-    let is_static_bit: u8 = if is_static { 1 } else { 0 };
-    let is_public_bit: u8 = if is_public { 1 << 1 } else { 0 };
-    let meta: u8 = is_static_bit | is_public_bit;
-    if token.token_type == TokenType::Punctuation(PunctuationType::SemiColon) {
-      return Ok(ast::NodeClassProperty {
+    if token.token_type == TokenType::Punctuation(PunctuationType::CircularBracketOpen) {
+      if is_const {
+        let line = self.source.lines().nth(name.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Un metodo no puede ser constante".to_string(),
+          location: name.location,
+          meta: format!("{}\0{}", line, name.value),
+        });
+      }
+      let params = self.parse_arguments_expr()?;
+      let return_type = self.parse_type_annotation()?;
+      let body = self.parse_function_body(true, &params, is_async, is_generator)?;
+      // the semicolon after a method is optional
+      self.match_token(TokenType::Punctuation(PunctuationType::SemiColon));
+      let params_len = params.len();
+      let function = ast::NodeFunction {
+        is_async,
+        is_generator,
+        attributes: List::new(),
         name: name.value.clone(),
-        value: None,
-        meta,
+        params,
+        return_type,
+        body,
+        location: token.location,
+        file: token.meta.clone(),
+      };
+      let kind = match accessor {
+        Some(accessor) => {
+          let (is_getter, expected) = if accessor.value == GETTER {
+            (true, 0)
+          } else {
+            (false, 1)
+          };
+          if params_len != expected || is_async || is_generator {
+            let line = self
+              .source
+              .lines()
+              .nth(accessor.location.start.line)
+              .unwrap();
+            return Err(ast::NodeError {
+              message: if is_async || is_generator {
+                format!(
+                  "Un '{}' no puede ser asincrono ni generador",
+                  accessor.value
+                )
+              } else {
+                format!(
+                  "Un '{}' debe recibir {} parametro(s)",
+                  accessor.value, expected
+                )
+              },
+              location: accessor.location,
+              meta: format!("{}\0{}", line, accessor.value),
+            });
+          }
+          if is_getter {
+            ast::NodeClassPropertyKind::Getter(function)
+          } else {
+            ast::NodeClassPropertyKind::Setter(function)
+          }
+        }
+        None if name.value == CONSTRUCTOR && !is_static => {
+          if is_async || is_generator {
+            let line = self.source.lines().nth(name.location.start.line).unwrap();
+            return Err(ast::NodeError {
+              message: "El constructor no puede ser asincrono ni generador".to_string(),
+              location: name.location,
+              meta: format!("{}\0{}", line, name.value),
+            });
+          }
+          ast::NodeClassPropertyKind::Constructor(function)
+        }
+        None => ast::NodeClassPropertyKind::Method(function),
+      };
+      return Ok(ast::NodeClassProperty {
+        name: name.value,
+        kind,
+        attributes: List::new(),
+        type_annotation: None,
+        is_static,
+        is_public,
+        is_const,
+        is_async,
+        location: name.location,
+        file: name.meta,
       });
     }
-    let value: ast::Node =
-      if token.token_type == TokenType::Punctuation(PunctuationType::CircularBracketOpen) {
-        let params = self.parse_arguments_expr()?;
-        let body = self.parse_block_expr(true, false, is_async)?;
-        ast::Node::Function(ast::NodeFunction {
-          is_async,
-          name: name.value.clone(),
-          params,
-          body,
-          location: token.location,
-          file: token.meta.clone(),
-        })
-      } else if token.token_type == TokenType::Operator(OperatorType::Equals) {
-        self.eat();
-        self.parse_expr()?
-      } else {
-        let line = self.source.lines().nth(token.location.start.line).unwrap();
+    if is_async || is_generator || accessor.is_some() {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: "Se esperaba un metodo".to_string(),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    }
+    let type_annotation = self.parse_type_annotation()?;
+    let token = self.at();
+    let value = if token.token_type == TokenType::Punctuation(PunctuationType::SemiColon) {
+      if is_const {
+        let line = self.source.lines().nth(name.location.start.line).unwrap();
         return Err(ast::NodeError {
-          message: "Se esperaba un valor".to_string(),
-          location: token.location,
-          meta: format!("{}\0{}", line, token.value),
+          message: "Una propiedad constante debe tener un valor".to_string(),
+          location: name.location,
+          meta: format!("{}\0{}", line, name.value),
         });
-      };
+      }
+      None
+    } else if token.token_type == TokenType::Operator(OperatorType::Equals) {
+      self.eat();
+      Some(self.parse_expr()?.to_box())
+    } else {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: "Se esperaba un valor".to_string(),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    };
     let semicolon = self.expect(TokenType::Punctuation(PunctuationType::SemiColon), "");
     if semicolon.token_type == TokenType::Error {
       return Err(ast::NodeError {
@@ -551,11 +1217,168 @@ impl Parser {
       });
     }
     Ok(ast::NodeClassProperty {
-      name: name.value.clone(),
-      value: Some(value.to_box()),
-      meta,
+      name: name.value,
+      kind: ast::NodeClassPropertyKind::Field(value),
+      attributes: List::new(),
+      type_annotation,
+      is_static,
+      is_public,
+      is_const,
+      is_async,
+      location: name.location,
+      file: name.meta,
     })
   }
+  /// `operador` followed by an operator and its parameters, otherwise it is
+  /// the name of a field or a method: `operador = 1;`
+  fn check_class_operator(&self) -> bool {
+    if self.at().token_type != TokenType::Identifier || self.at().value != OPERATOR {
+      return false;
+    }
+    let mut length = 1;
+    while let TokenType::Operator(_) = self.look(length).token_type {
+      let prev = self.look(length - 1);
+      let current = self.look(length);
+      if length > 1
+        && (current.location.start.line != prev.location.start.line
+          || current.location.start.column != prev.location.end.column)
+      {
+        break;
+      }
+      length += 1;
+    }
+    let is_assign =
+      length == 2 && self.next().token_type == TokenType::Operator(OperatorType::Equals);
+    length > 1
+      && !is_assign
+      && self.look(length).token_type
+        == TokenType::Punctuation(PunctuationType::CircularBracketOpen)
+  }
+  fn parse_class_operator(
+    &mut self,
+    is_public: bool,
+  ) -> Result<ast::NodeClassProperty, ast::NodeError> {
+    let keyword = self.eat(); // operador
+    let token = self.eat();
+    let mut symbol = token.value.clone();
+    while let TokenType::Operator(operator) = self.at().token_type {
+      if !self.match_join_token(TokenType::Operator(operator)) {
+        break;
+      }
+      symbol.push_str(&self.prev().value);
+    }
+    // the operators and the number of parameters they can receive
+    let (operator, arity): (ast::NodeOperator, &[usize]) = match symbol.as_str() {
+      "+" => (ast::NodeOperator::Plus, &[0, 1]),
+      "-" => (ast::NodeOperator::Minus, &[0, 1]),
+      "~" => (ast::NodeOperator::Approximate, &[0, 1]),
+      "!" => (ast::NodeOperator::Not, &[0]),
+      "*" => (ast::NodeOperator::Multiply, &[1]),
+      "/" => (ast::NodeOperator::Division, &[1]),
+      "//" => (ast::NodeOperator::FloorDivision, &[1]),
+      "%" => (ast::NodeOperator::Modulo, &[1]),
+      "^" => (ast::NodeOperator::Exponential, &[1]),
+      "==" => (ast::NodeOperator::Equal, &[1]),
+      "!=" => (ast::NodeOperator::NotEqual, &[1]),
+      "<" => (ast::NodeOperator::LessThan, &[1]),
+      "<=" => (ast::NodeOperator::LessThanOrEqual, &[1]),
+      ">" => (ast::NodeOperator::GreaterThan, &[1]),
+      ">=" => (ast::NodeOperator::GreaterThanOrEqual, &[1]),
+      "??" => (ast::NodeOperator::Nullish, &[1]),
+      "&" => (ast::NodeOperator::BitAnd, &[1]),
+      "|" => (ast::NodeOperator::BitOr, &[1]),
+      "<<" => (ast::NodeOperator::BitMoveLeft, &[1]),
+      ">>" => (ast::NodeOperator::BitMoveRight, &[1]),
+      _ => {
+        let line = self.source.lines().nth(token.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: format!("El operador '{}' no se puede sobrecargar", symbol),
+          location: token.location,
+          meta: format!("{}\0{}", line, symbol),
+        });
+      }
+    };
+    let params = self.parse_arguments_expr()?;
+    if !arity.contains(&params.len()) {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      let expected = arity
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" o ");
+      return Err(ast::NodeError {
+        message: format!(
+          "El operador '{}' debe recibir {} parametro(s)",
+          symbol, expected
+        ),
+        location: token.location,
+        meta: format!("{}\0{}", line, symbol),
+      });
+    }
+    let return_type = self.parse_type_annotation()?;
+    let body = self.parse_function_body(true, &params, false, false)?;
+    self.match_token(TokenType::Punctuation(PunctuationType::SemiColon));
+    let function = ast::NodeFunction {
+      is_async: false,
+      is_generator: false,
+      attributes: List::new(),
+      name: symbol.clone(),
+      params,
+      return_type,
+      body,
+      location: token.location.clone(),
+      file: token.meta.clone(),
+    };
+    Ok(ast::NodeClassProperty {
+      name: symbol,
+      kind: ast::NodeClassPropertyKind::Operator(operator, function),
+      attributes: List::new(),
+      type_annotation: None,
+      is_static: false,
+      is_public,
+      is_const: false,
+      is_async: false,
+      location: keyword.location,
+      file: keyword.meta,
+    })
+  }
+  fn check_class_prop(
+    &self,
+    members: &mut Vec<(String, bool, u8)>,
+    prop: &ast::NodeClassProperty,
+  ) -> Result<(), ast::NodeError> {
+    // 0: field or method, 1: getter, 2: setter, 3: constructor,
+    // 4: unary operator, 5: binary operator
+    let slot: u8 = match prop.kind {
+      ast::NodeClassPropertyKind::StaticBlock(_) => return Ok(()),
+      ast::NodeClassPropertyKind::Getter(_) => 1,
+      ast::NodeClassPropertyKind::Setter(_) => 2,
+      ast::NodeClassPropertyKind::Constructor(_) => 3,
+      ast::NodeClassPropertyKind::Operator(_, ref function) => 4 + function.params.len() as u8,
+      _ => 0,
+    };
+    let is_duplicated = members.iter().any(|(name, is_static, other)| {
+      *name == prop.name
+        && *is_static == prop.is_static
+        && (slot == 0 || *other == 0 || *other == slot)
+    });
+    if is_duplicated {
+      let line = self.source.lines().nth(prop.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: if slot == 3 {
+          "La clase ya tiene un constructor".to_string()
+        } else if slot >= 4 {
+          format!("El operador '{}' ya esta definido en la clase", prop.name)
+        } else {
+          format!("El miembro '{}' ya esta definido en la clase", prop.name)
+        },
+        location: prop.location.clone(),
+        meta: format!("{}\0{}", line, prop.name),
+      });
+    }
+    members.push((prop.name.clone(), prop.is_static, slot));
+    Ok(())
+  }
   fn parse_class_decl(&mut self) -> Result<ast::Node, NodeError> {
     let token = self.eat(); // clase
     let name = self.expect(TokenType::Identifier, "Se esperaba un identificador");
@@ -589,6 +1412,28 @@ impl Parser {
       None
     };
 
+    let mut implements = List::new();
+    if self.match_token(TokenType::Keyword(KeywordsType::Implements)) {
+      loop {
+        let interface = self.expect(TokenType::Identifier, "Se esperaba un identificador");
+        if interface.token_type == TokenType::Error {
+          return Err(ast::NodeError {
+            message: interface.value,
+            location: interface.location,
+            meta: interface.meta,
+          });
+        }
+        implements.push(ast::NodeIdentifier {
+          name: interface.value,
+          location: interface.location,
+          file: interface.meta,
+        });
+        if !self.match_token(TokenType::Punctuation(PunctuationType::Comma)) {
+          break;
+        }
+      }
+    }
+
     let open_brace = self.expect(
       TokenType::Punctuation(PunctuationType::RegularBracketOpen),
       "",
@@ -600,37 +1445,53 @@ impl Parser {
         meta: open_brace.meta,
       });
     }
+    // the field initializers are not part of the enclosing generator or async function
+    let in_generator = std::mem::replace(&mut self.in_generator, false);
+    let in_async = std::mem::replace(&mut self.in_async, false);
+    let body = self.parse_class_body();
+    self.in_generator = in_generator;
+    self.in_async = in_async;
+    ast::Node::Class(ast::NodeClass {
+      name: name.value.clone(),
+      attributes: List::new(),
+      extend_of,
+      implements,
+      body: body?,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_class_body(&mut self) -> Result<List<ast::NodeClassProperty>, NodeError> {
     let mut body: List<ast::NodeClassProperty> = List::new();
+    let mut members = Vec::new();
     while !(self.is_eof()
       || self.match_token(TokenType::Punctuation(PunctuationType::RegularBracketClose)))
     {
+      let attributes = self.parse_attributes()?;
       let modifier = self.get_modifier();
 
       if modifier.is_err() {
         return Err(modifier.err().unwrap());
       }
 
-      let (is_static, is_public) = modifier.ok().unwrap();
+      let (is_static, is_public, is_const) = modifier.ok().unwrap();
 
-      let prop = self.parse_class_prop(is_static, is_public);
+      let prop = self.parse_class_prop(is_static, is_public, is_const);
       if prop.is_err() {
         return Err(prop.err().unwrap());
       }
-      let prop = prop.ok().unwrap();
+      let mut prop = prop.ok().unwrap();
+      prop.attributes = attributes;
+      self.check_class_prop(&mut members, &prop)?;
       body.push(prop);
     }
-    ast::Node::Class(ast::NodeClass {
-      name: name.value.clone(),
-      extend_of,
-      body,
-      location: token.location,
-      file: token.meta,
-    })
-    .into()
+    Ok(body)
   }
-  fn get_modifier(&mut self) -> Result<(bool, bool), ast::NodeError> {
+  fn get_modifier(&mut self) -> Result<(bool, bool, bool), ast::NodeError> {
     let mut is_static = false;
     let mut is_public = false;
+    let mut is_const = false;
     while !self.is_eof() {
       let token = self.at();
       if token.token_type == TokenType::Error {
@@ -665,9 +1526,21 @@ impl Parser {
         is_public = true;
         continue;
       }
+      if self.match_token(TokenType::Keyword(KeywordsType::Constant)) {
+        if is_const {
+          let line = self.source.lines().nth(token.location.start.line).unwrap();
+          return Err(ast::NodeError {
+            message: "Modificador duplicado".to_string(),
+            location: token.location,
+            meta: format!("{}\0{}", line, token.value),
+          });
+        }
+        is_const = true;
+        continue;
+      }
       break;
     }
-    Ok((is_static, is_public))
+    Ok((is_static, is_public, is_const))
   }
   fn parse_simple_decl(
     &mut self,
@@ -714,6 +1587,23 @@ impl Parser {
             meta: format!("{}\0{}", line, token.value),
           });
         }
+        let label = if self.check_token(TokenType::Identifier) {
+          let label = self.eat();
+          if !self.labels.contains(&label.value) {
+            let line = self.source.lines().nth(label.location.start.line).unwrap();
+            return Err(ast::NodeError {
+              message: format!(
+                "No se encontro la etiqueta '{}' en un ciclo superior",
+                label.value
+              ),
+              location: label.location,
+              meta: format!("{}\0{}", line, label.value),
+            });
+          }
+          Some(label.value)
+        } else {
+          None
+        };
         let semicolon = self.expect(TokenType::Punctuation(PunctuationType::SemiColon), "");
         if semicolon.token_type == TokenType::Error {
           return Err(ast::NodeError {
@@ -729,6 +1619,7 @@ impl Parser {
         };
         ast::Node::LoopEdit(ast::NodeLoopEdit {
           action,
+          label,
           location: token.location,
           file: token.meta,
         })
@@ -768,6 +1659,11 @@ impl Parser {
       }
       TokenType::Keyword(KeywordsType::Try) => self.parse_try_decl(is_function, is_loop, is_async),
       TokenType::Keyword(KeywordsType::Class) => self.parse_class_decl(),
+      TokenType::Keyword(KeywordsType::Enum) => self.parse_enum_decl(),
+      TokenType::Keyword(KeywordsType::Interface) => self.parse_interface_decl(),
+      TokenType::Keyword(KeywordsType::Using) => {
+        self.parse_using_decl(is_function, is_loop, is_async)
+      }
       TokenType::Keyword(KeywordsType::Console) => {
         self.eat();
         let operator = if self.match_token(TokenType::Operator(OperatorType::LessThan)) {
@@ -818,16 +1714,6 @@ impl Parser {
         })
         .into()
       }
-      TokenType::Keyword(KeywordsType::Await) => {
-        self.eat();
-        let expression = self.parse_expr()?.to_box();
-        ast::Node::Await(ast::NodeExpressionMedicator {
-          expression,
-          location: token.location,
-          file: token.meta,
-        })
-        .into()
-      }
       TokenType::Error => {
         self.eat();
         Err(ast::NodeError {
@@ -859,6 +1745,13 @@ impl Parser {
         meta: open_paren.meta,
       });
     }
+    if self.check_token(TokenType::Identifier)
+      && self.next().token_type == TokenType::Keyword(KeywordsType::In)
+    {
+      return self.parse_for_each_decl(token, is_function, is_async);
+    }
+    // the variables of the loop belong to a new scope
+    self.bindings.push(Vec::new());
     let init = self.parse_var_decl()?.to_box();
     let condition = self.parse_expr()?;
     let semicolon = self.expect(TokenType::Punctuation(PunctuationType::SemiColon), "");
@@ -882,6 +1775,7 @@ impl Parser {
       });
     }
     let block = self.parse_block_expr(is_function, true, is_async);
+    self.bindings.pop();
     if block.is_err() {
       return Err(block.err().unwrap());
     }
@@ -891,6 +1785,41 @@ impl Parser {
       condition: Box::new(condition),
       update: Box::new(update),
       body,
+      label: None,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_for_each_decl(
+    &mut self,
+    token: Token<TokenType>,
+    is_function: bool,
+    is_async: bool,
+  ) -> Result<ast::Node, NodeError> {
+    let identifier = self.eat().value;
+    self.eat(); // en
+    let iterable = self.parse_expr()?;
+    let close_paren = self.expect(
+      TokenType::Punctuation(PunctuationType::CircularBracketClose),
+      "",
+    );
+    if close_paren.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: "Se esperaba un paréntesis de cierre".to_string(),
+        location: close_paren.location,
+        meta: close_paren.meta,
+      });
+    }
+    self.bindings.push(vec![(identifier.clone(), false)]);
+    let body = self.parse_block_expr(is_function, true, is_async);
+    self.bindings.pop();
+    let body = body?;
+    ast::Node::ForEach(ast::NodeForEach {
+      identifier,
+      iterable: iterable.to_box(),
+      body,
+      label: None,
       location: token.location,
       file: token.meta,
     })
@@ -908,46 +1837,27 @@ impl Parser {
       return Err(block.err().unwrap());
     }
     let body = block.ok().unwrap();
-    let catch = if self.at().token_type == TokenType::Keyword(KeywordsType::Catch) {
-      self.eat();
-      let open_paren = self.expect(
-        TokenType::Punctuation(PunctuationType::CircularBracketOpen),
-        "",
-      );
-      if open_paren.token_type == TokenType::Error {
-        return Err(ast::NodeError {
-          message: "Se esperaba un paréntesis de apertura".to_string(),
-          location: open_paren.location,
-          meta: open_paren.meta,
-        });
-      }
-      let identifier = self.expect(TokenType::Identifier, "");
-      if identifier.token_type == TokenType::Error {
+    let mut catch = List::new();
+    let mut catch_all: Option<util::Location> = None;
+    while self.at().token_type == TokenType::Keyword(KeywordsType::Catch) {
+      let clause = self.parse_catch_clause(is_function, is_loop, is_async)?;
+      if let Some(previous) = catch_all {
+        let line = self.source.lines().nth(clause.location.start.line).unwrap();
         return Err(ast::NodeError {
-          message: identifier.value.clone(),
-          location: identifier.location,
-          meta: identifier.meta,
-        });
-      }
-      let close_paren = self.expect(
-        TokenType::Punctuation(PunctuationType::CircularBracketClose),
-        "",
-      );
-      if close_paren.token_type == TokenType::Error {
-        return Err(ast::NodeError {
-          message: "Se esperaba un paréntesis de cierre".to_string(),
-          location: close_paren.location,
-          meta: close_paren.meta,
+          message: format!(
+            "Esta '{}' nunca se ejecuta, la de la linea {} captura todos los errores",
+            KeywordsType::Catch.as_str(),
+            previous.start.line + 1
+          ),
+          location: clause.location,
+          meta: format!("{}\0{}", line, KeywordsType::Catch.as_str()),
         });
       }
-      let block = self.parse_block_expr(is_function, is_loop, is_async);
-      if block.is_err() {
-        return Err(block.err().unwrap());
+      if clause.is_catch_all() {
+        catch_all = Some(clause.location.clone());
       }
-      Some((identifier.value.clone(), block.ok().unwrap()))
-    } else {
-      None
-    };
+      catch.push(clause);
+    }
     let finally = if self.at().token_type == TokenType::Keyword(KeywordsType::Finally) {
       self.eat();
       let block = self.parse_block_expr(is_function, is_loop, is_async);
@@ -967,8 +1877,75 @@ impl Parser {
     })
     .into()
   }
+  /// captura (error: Tipo) si condicion {} | captura {}
+  fn parse_catch_clause(
+    &mut self,
+    is_function: bool,
+    is_loop: bool,
+    is_async: bool,
+  ) -> Result<ast::NodeCatch, NodeError> {
+    let token = self.eat(); // captura
+    let (name, class) =
+      if self.match_token(TokenType::Punctuation(PunctuationType::CircularBracketOpen)) {
+        let identifier = self.expect(TokenType::Identifier, "");
+        if identifier.token_type == TokenType::Error {
+          return Err(ast::NodeError {
+            message: identifier.value.clone(),
+            location: identifier.location,
+            meta: identifier.meta,
+          });
+        }
+        let class = self.parse_type_annotation()?;
+        let close_paren = self.expect(
+          TokenType::Punctuation(PunctuationType::CircularBracketClose),
+          "",
+        );
+        if close_paren.token_type == TokenType::Error {
+          return Err(ast::NodeError {
+            message: "Se esperaba un paréntesis de cierre".to_string(),
+            location: close_paren.location,
+            meta: close_paren.meta,
+          });
+        }
+        (Some(identifier.value), class)
+      } else {
+        (None, None)
+      };
+    self.bindings.push(match &name {
+      Some(name) => vec![(name.clone(), false)],
+      None => Vec::new(),
+    });
+    let clause = self.parse_catch_body(is_function, is_loop, is_async);
+    self.bindings.pop();
+    let (guard, body) = clause?;
+    Ok(ast::NodeCatch {
+      name,
+      class,
+      guard,
+      body,
+      location: token.location,
+      file: token.meta,
+    })
+  }
+  fn parse_catch_body(
+    &mut self,
+    is_function: bool,
+    is_loop: bool,
+    is_async: bool,
+  ) -> Result<(Option<ast::BNode>, ast::NodeBlock), NodeError> {
+    let guard = if self.match_token(TokenType::Keyword(KeywordsType::If)) {
+      Some(self.parse_expr()?.to_box())
+    } else {
+      None
+    };
+    let body = self.parse_block_expr(is_function, is_loop, is_async)?;
+    Ok((guard, body))
+  }
   fn parse_function_decl(&mut self, is_async: bool) -> Result<ast::Node, NodeError> {
     let token = self.eat(); // fn
+
+    // fn* nombre() {}
+    let is_generator = self.match_token(TokenType::Operator(OperatorType::Star));
     let name = self.expect(TokenType::Identifier, "Se esperaba un identificador");
     if name.token_type == TokenType::Error {
       return Err(ast::NodeError {
@@ -982,18 +1959,84 @@ impl Parser {
       return Err(params.err().unwrap());
     }
     let params = params.ok().unwrap();
-    let body = self.parse_block_expr(true, false, is_async)?;
+    let return_type = self.parse_type_annotation()?;
+    let body = self.parse_function_body(true, &params, is_async, is_generator)?;
     ast::Node::Function(ast::NodeFunction {
       is_async,
+      is_generator,
+      attributes: List::new(),
       name: name.value.clone(),
       params,
+      return_type,
       body,
       location: token.location,
       file: token.meta,
     })
     .into()
   }
-  fn parse_arguments_expr(&mut self) -> Result<List<ast::NodeIdentifier>, ast::NodeError> {
+  /// the body of a function, a method or a static block: the labels and the
+  /// generator context of the enclosing code don't reach it
+  fn parse_function_body(
+    &mut self,
+    in_function: bool,
+    params: &List<ast::NodeParameter>,
+    is_async: bool,
+    is_generator: bool,
+  ) -> Result<NodeBlock, NodeError> {
+    let labels = std::mem::take(&mut self.labels);
+    let in_generator = std::mem::replace(&mut self.in_generator, is_generator);
+    self.declare_params(params);
+    let body = self.parse_block_expr(in_function, false, is_async);
+    self.bindings.pop();
+    self.labels = labels;
+    self.in_generator = in_generator;
+    body
+  }
+  fn parse_type_annotation(&mut self) -> Result<Option<ast::NodeType>, ast::NodeError> {
+    if !self.match_token(TokenType::Punctuation(PunctuationType::DoubleDot)) {
+      return Ok(None);
+    }
+    Ok(Some(self.parse_type_expr()?))
+  }
+  fn parse_type_expr(&mut self) -> Result<ast::NodeType, ast::NodeError> {
+    let name = self.expect(TokenType::Identifier, "Se esperaba un tipo");
+    if name.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: name.value.clone(),
+        location: name.location,
+        meta: name.meta,
+      });
+    }
+    let mut arguments = List::new();
+    // Lista<Numero>, Mapa<Cadena, Numero>
+    if self.match_token(TokenType::Operator(OperatorType::LessThan)) {
+      loop {
+        arguments.push(self.parse_type_expr()?);
+        if self.match_token(TokenType::Punctuation(PunctuationType::Comma)) {
+          continue;
+        }
+        let close = self.expect(
+          TokenType::Operator(OperatorType::GreaterThan),
+          "Se esperaba un '>' para cerrar el tipo",
+        );
+        if close.token_type == TokenType::Error {
+          return Err(ast::NodeError {
+            message: close.value,
+            location: close.location,
+            meta: close.meta,
+          });
+        }
+        break;
+      }
+    }
+    Ok(ast::NodeType {
+      name: name.value,
+      arguments,
+      location: name.location,
+      file: name.meta,
+    })
+  }
+  fn parse_arguments_expr(&mut self) -> Result<List<ast::NodeParameter>, ast::NodeError> {
     let open_paren = self.expect(
       TokenType::Punctuation(PunctuationType::CircularBracketOpen),
       "",
@@ -1019,8 +2062,10 @@ impl Parser {
           meta: param.meta,
         });
       }
-      params.push(ast::NodeIdentifier {
+      let type_annotation = self.parse_type_annotation()?;
+      params.push(ast::NodeParameter {
         name: param.value.clone(),
+        type_annotation,
         location: param.location,
         file: param.meta,
       });
@@ -1051,32 +2096,30 @@ impl Parser {
     let token = self.eat(); // si
     let condition = self.parse_expr()?;
     let body = self.parse_block_expr(is_function, is_loop, is_async)?;
-    let else_token = self.at(); // ent
-    if else_token.token_type == TokenType::Keyword(KeywordsType::Else) {
-      self.eat();
-      let else_block = self.parse_block_expr(is_function, is_loop, is_async);
-      if else_block.is_err() {
-        return Err(else_block.err().unwrap());
+    let mut else_if = List::new();
+    let mut else_body = None;
+    while self.match_token(TokenType::Keyword(KeywordsType::Else)) {
+      // ent si condicion {}
+      if self.at().token_type == TokenType::Keyword(KeywordsType::If) {
+        let if_token = self.eat();
+        let condition = self.parse_expr()?;
+        let body = self.parse_block_expr(is_function, is_loop, is_async)?;
+        else_if.push(ast::NodeElseIf {
+          condition: condition.to_box(),
+          body,
+          location: if_token.location,
+          file: if_token.meta,
+        });
+        continue;
       }
-      let else_body = else_block.ok().unwrap();
-      let else_body = if else_body.len() == 0 {
-        None
-      } else {
-        Some(else_body)
-      };
-      return ast::Node::If(ast::NodeIf {
-        condition: condition.to_box(),
-        body,
-        else_body,
-        location: token.location,
-        file: token.meta,
-      })
-      .into();
+      else_body = Some(self.parse_block_expr(is_function, is_loop, is_async)?);
+      break;
     }
     ast::Node::If(ast::NodeIf {
       condition: condition.to_box(),
       body,
-      else_body: None,
+      else_if,
+      else_body,
       location: token.location,
       file: token.meta,
     })
@@ -1115,6 +2158,7 @@ impl Parser {
     ast::Node::DoWhile(ast::NodeWhile {
       condition,
       body,
+      label: None,
       location: token.location,
       file: token.meta,
     })
@@ -1135,6 +2179,7 @@ impl Parser {
     ast::Node::While(ast::NodeWhile {
       condition: condition.to_box(),
       body,
+      label: None,
       location: token.location,
       file: token.meta,
     })
@@ -1170,10 +2215,15 @@ impl Parser {
       }
       let expr = expr.unwrap()?;
       let mut body = List::new();
+      let mut hoisted = List::new();
       let location = expr.get_location();
+      if let Some(name) = ast::hoisted_name(&expr) {
+        hoisted.push(name);
+      }
       body.push(expr);
       return Ok(ast::NodeBlock {
         body,
+        hoisted,
         in_function,
         in_loop,
         location,
@@ -1195,8 +2245,9 @@ impl Parser {
     is_async: bool,
     stop_with: TokenType,
   ) -> Result<NodeBlock, ast::NodeError> {
-    let mut functions = Vec::new();
-    let mut code = Vec::new();
+    let mut body = List::new();
+    let mut hoisted = List::new();
+    self.bindings.push(Vec::new());
     loop {
       let is_eof = self.is_eof();
       let is_stop = self.match_token(stop_with);
@@ -1206,21 +2257,16 @@ impl Parser {
       let stmt = self.parse_stmt(is_global_scope, is_function, is_loop, is_async);
       if let Some(stmt) = stmt {
         let stmt = stmt?;
-        match stmt {
-          ast::Node::Function(_) => functions.push(stmt),
-          ast::Node::Export(ref export) => match export.value.as_ref() {
-            ast::Node::Function(_) => functions.push(stmt.clone()),
-            _ => code.push(stmt),
-          },
-          _ => code.push(stmt),
+        if let Some(name) = ast::hoisted_name(&stmt) {
+          hoisted.push(name);
         }
+        body.push(stmt);
       }
     }
-    let mut body = List::new();
-    body.append_vec(&mut functions);
-    body.append_vec(&mut code);
+    self.bindings.pop();
     Ok(ast::NodeBlock {
       body,
+      hoisted,
       in_function: false,
       in_loop: false,
       location: util::Location {
@@ -1232,109 +2278,73 @@ impl Parser {
     })
   }
   fn parse_var_decl(&mut self) -> Result<ast::Node, NodeError> {
-    let token = self.eat();
-    let is_const = token.value == "const";
-    let mut semi_token = SemiToken {
-      value: token.value,
-      location: token.location.clone(),
-    };
-
-    let identifier = self.expect(TokenType::Identifier, "Se esperaba un identificador");
-    if semi_token.location.start.line == identifier.location.start.line {
-      semi_token.value += " "
-        .repeat(identifier.location.start.column - semi_token.location.start.column)
-        .as_str();
-    } else {
-      semi_token.value = "".to_string();
-    };
-    semi_token.location.start.line = identifier.location.start.line;
-    semi_token.location.start.column = identifier.location.start.column;
-    if identifier.token_type == TokenType::Error {
-      let line = self
-        .source
-        .lines()
-        .nth(semi_token.location.start.line)
-        .unwrap();
-      let meta = format!("{}\0{}", line, semi_token.value);
+    let node = self.parse_declarators()?;
+    let semicolon = self.expect(
+      TokenType::Punctuation(PunctuationType::SemiColon),
+      "Se esperaba un punto y coma (variable)",
+    );
+    if semicolon.token_type == TokenType::Error {
       return Err(ast::NodeError {
-        message: identifier.value,
-        location: semi_token.location,
-        meta,
+        message: semicolon.value,
+        location: semicolon.location,
+        meta: semicolon.meta,
       });
     }
-    semi_token.value += identifier.value.as_str();
-
-    let equals_semicolon = self.eat();
-    if semi_token.location.start.line == equals_semicolon.location.start.line {
-      semi_token.value += " "
-        .repeat(equals_semicolon.location.start.column - semi_token.location.start.column)
-        .as_str();
-    } else {
-      semi_token.value = "".to_string();
-    };
-    semi_token.location.start.line = equals_semicolon.location.start.line;
-    semi_token.location.start.column = equals_semicolon.location.start.column;
-    if equals_semicolon.token_type == TokenType::Punctuation(PunctuationType::SemiColon) {
-      return ast::Node::VarDecl(ast::NodeVarDecl {
-        name: identifier.value.clone(),
-        value: None,
-        is_const,
+    ast::Node::VarDecl(node).into()
+  }
+  /// def a = 1, b (without the semicolon)
+  fn parse_declarators(&mut self) -> Result<ast::NodeVarDecl, NodeError> {
+    let token = self.eat();
+    let is_const = token.token_type == TokenType::Keyword(KeywordsType::Constant);
+    let mut declarators = List::new();
+    loop {
+      let identifier = self.expect(TokenType::Identifier, "Se esperaba un identificador");
+      if identifier.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: identifier.value,
+          location: identifier.location,
+          meta: identifier.meta,
+        });
+      }
+      let type_annotation = self.parse_type_annotation()?;
+      let value = if self.match_token(TokenType::Operator(OperatorType::Equals)) {
+        Some(self.parse_expr()?.to_box())
+      } else {
+        None
+      };
+      if is_const && value.is_none() {
+        let line = self
+          .source
+          .lines()
+          .nth(identifier.location.start.line)
+          .unwrap();
+        return Err(ast::NodeError {
+          message: "Una constante debe tener un valor".to_string(),
+          location: identifier.location,
+          meta: format!("{}\0{}", line, identifier.value),
+        });
+      }
+      self.declare(&identifier.value, is_const);
+      declarators.push(ast::NodeDeclarator {
+        name: identifier.value,
+        value,
+        type_annotation,
         location: identifier.location,
         file: identifier.meta,
-      })
-      .into();
-    }
-    if equals_semicolon.token_type != TokenType::Operator(OperatorType::Equals) {
-      let line = self
-        .source
-        .lines()
-        .nth(semi_token.location.start.line)
-        .unwrap();
-      let meta = format!("{}\0{}", line, semi_token.value);
-      return Err(ast::NodeError {
-        message: format!("Se esperaba un punto y coma (variable e)"),
-        location: semi_token.location,
-        meta,
-      });
-    }
-    semi_token.value += equals_semicolon.value.as_str();
-
-    let value = self.parse_expr()?;
-    if semi_token.location.start.line == value.get_location().start.line {
-      semi_token.value += " "
-        .repeat(value.get_location().start.column - semi_token.location.start.column)
-        .as_str();
-    } else {
-      semi_token.value = "".to_string();
-    };
-    semi_token.location.start.column = value.get_location().start.column;
-    let semicolon = self.expect(TokenType::Punctuation(PunctuationType::SemiColon), "");
-    if semi_token.location.start.line == semicolon.location.start.line {
-      semi_token.value += " "
-        .repeat(semicolon.location.start.column - semi_token.location.start.column)
-        .as_str();
-    } else {
-      semi_token.value = "".to_string();
-    };
-    semi_token.location.start.column += 1;
-    if semicolon.token_type == TokenType::Error {
-      return Err(ast::NodeError {
-        message: format!("Se esperaba un punto y coma (variable v)"),
-        location: semi_token.location,
-        meta: semi_token.value,
       });
+      if !self.match_token(TokenType::Punctuation(PunctuationType::Comma)) {
+        break;
+      }
     }
-    ast::Node::VarDecl(ast::NodeVarDecl {
-      name: identifier.value.clone(),
-      value: Some(value.to_box()),
+    Ok(ast::NodeVarDecl {
+      declarators,
       is_const,
       location: token.location,
       file: token.meta,
     })
-    .into()
   }
   fn parse_stmt_expr(&mut self) -> Result<ast::Node, NodeError> {
-    let node = self.parse_expr();
+    let node = self.parse_expr()?;
     let semicolon = self.expect(
       TokenType::Punctuation(PunctuationType::SemiColon),
       "Se esperaba un punto y coma (expr)",
@@ -1346,18 +2356,57 @@ impl Parser {
         meta: semicolon.meta,
       });
     }
-    node
+    Ok(node)
   }
   fn parse_expr(&mut self) -> Result<ast::Node, NodeError> {
-    let left = self.parse_math_lineal_expr()?;
-    self.parse_complex_expr(left)
+    let in_operand = std::mem::replace(&mut self.in_operand, false);
+    let value = self.parse_pipeline_expr();
+    self.in_operand = in_operand;
+    value
+  }
+  /// the pipeline is only above the assignment, `x = a |> f` is `x = (a |> f)`
+  fn parse_pipeline_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let mut value = self.parse_operation_expr()?;
+    // x |> a |> b is (x |> a) |> b
+    while self.check_pipeline() {
+      self.eat(); // |
+      self.eat(); // >
+      let stage = self.parse_operand_expr()?;
+      value = ast::Node::Pipeline(ast::NodePipeline {
+        value: value.clone().to_box(),
+        stage: stage.to_box(),
+        location: value.get_location(),
+        file: value.get_file(),
+      });
+    }
+    Ok(value)
+  }
+  /// the right side of an operator or a pipeline stage, it can't be an assignment
+  fn parse_operand_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let in_operand = std::mem::replace(&mut self.in_operand, true);
+    let value = self.parse_operation_expr();
+    self.in_operand = in_operand;
+    value
+  }
+  /// an expression without pipelines
+  fn parse_operation_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let operation_start = std::mem::replace(&mut self.operation_start, self.index);
+    let operation = self.parse_math_lineal_expr();
+    let operation = match operation {
+      Ok(left) => self.parse_complex_expr(left),
+      Err(e) => Err(e),
+    };
+    self.operation_start = operation_start;
+    operation
   }
   fn parse_math_lineal_expr(&mut self) -> Result<ast::Node, NodeError> {
     let mut left = self.parse_math_multiplicative_expr()?;
     loop {
       let token = self.at();
       if let TokenType::Operator(OperatorType::Plus | OperatorType::Minus) = token.token_type {
-        if self.next().token_type == TokenType::Operator(OperatorType::Equals) {
+        if self.next().token_type == TokenType::Operator(OperatorType::Equals)
+          || self.check_update_operator().is_some()
+        {
           return Ok(left);
         }
       } else {
@@ -1424,6 +2473,7 @@ impl Parser {
     }
   }
   fn parse_math_exponential_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let start = self.at();
     let left = self.parse_literal_expr().unwrap_or_else(|token| {
       let line = self.source.lines().nth(token.location.start.line).unwrap();
       Err(ast::NodeError {
@@ -1432,6 +2482,7 @@ impl Parser {
         meta: format!("{}\0{}", line, token.value),
       })
     })?;
+    let left = self.parse_update_expr(left, &start)?;
     let token = self.at();
     if token.token_type != TokenType::Operator(OperatorType::Exponential)
       || self.next().token_type == TokenType::Operator(OperatorType::Equals)
@@ -1443,6 +2494,7 @@ impl Parser {
     } else {
       return Ok(left);
     };
+    let start = self.at();
     let right = self.parse_literal_expr().unwrap_or_else(|token| {
       let line = self.source.lines().nth(token.location.start.line).unwrap();
       Err(ast::NodeError {
@@ -1451,6 +2503,7 @@ impl Parser {
         meta: format!("{}\0{}", line, token.value),
       })
     })?;
+    let right = self.parse_update_expr(right, &start)?;
     ast::Node::Binary(ast::NodeBinary {
       operator,
       left: left.clone().to_box(),
@@ -1460,8 +2513,44 @@ impl Parser {
     })
     .into()
   }
+  /// the members and calls of an operand and its postfix ++ or --
+  fn parse_update_expr(
+    &mut self,
+    operand: ast::Node,
+    start: &Token<TokenType>,
+  ) -> Result<ast::Node, NodeError> {
+    let operand = self.parse_postfix_expr(operand)?;
+    let operator = match self.check_update_operator() {
+      Some(operator) => operator,
+      None => return Ok(operand),
+    };
+    let token = self.eat();
+    self.eat();
+    self.check_assignment_target(&operand, false, &start.location, &token)?;
+    ast::Node::UnaryBack(ast::NodeUnary {
+      operator,
+      operand: operand.clone().to_box(),
+      location: operand.get_location(),
+      file: operand.get_file(),
+    })
+    .into()
+  }
   fn parse_complex_expr(&mut self, left: ast::Node) -> Result<ast::Node, NodeError> {
     let left = self.parse_back_unary_expr(left.clone())?;
+    if self.check_range() {
+      return self.parse_range_expr(left);
+    }
+    // the pipeline has the lowest precedence, it is parsed by parse_expr
+    if self.check_pipeline() {
+      return Ok(left);
+    }
+    if self.check_in_tokens(vec![
+      TokenType::Keyword(KeywordsType::Is),
+      TokenType::Keyword(KeywordsType::In),
+    ]) {
+      let relation = self.parse_relational_expr(left)?;
+      return self.parse_complex_expr(relation);
+    }
     let token = self.at();
     if token.token_type == TokenType::Error {
       return Err(ast::NodeError {
@@ -1647,35 +2736,214 @@ impl Parser {
       ast::NodeOperator::Assign => (ast::NodeOperator::None, true),
       x => (x, false),
     };
-    let right = if operator == ast::NodeOperator::None && is_assignment {
-      self.parse_expr()?
-    } else if operator == ast::NodeOperator::None {
-      return left.into();
-    } else {
-      let right = self.parse_expr()?;
-      ast::Node::Binary(ast::NodeBinary {
+    if !is_assignment {
+      if operator == ast::NodeOperator::None {
+        return left.into();
+      }
+      let right = self.parse_operand_expr()?;
+      return ast::Node::Binary(ast::NodeBinary {
         operator,
         left: left.clone().to_box(),
         right: right.to_box(),
         location: left.get_location(),
         file: left.get_file(),
       })
-    };
-    if is_assignment {
-      ast::Node::Assignment(ast::NodeAssignment {
-        identifier: left.clone().to_box(),
-        value: right.to_box(),
+      .into();
+    }
+    // a + b = c and x |> f = c would assign to the operation
+    if self.in_operand {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: "No se puede asignar el resultado de una operacion".to_string(),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    }
+    // the destructuring patterns can only be used with '='
+    let start = self.tokens[self.operation_start].location.clone();
+    self.check_assignment_target(&left, operator == ast::NodeOperator::None, &start, &token)?;
+    // the value of an assignment is a full expression, x += a |> f is x += (a |> f)
+    let value = self.parse_expr()?;
+    let value = if operator == ast::NodeOperator::None {
+      value
+    } else {
+      ast::Node::Binary(ast::NodeBinary {
+        operator,
+        left: left.clone().to_box(),
+        right: value.to_box(),
         location: left.get_location(),
         file: left.get_file(),
       })
-      .into()
+    };
+    ast::Node::Assignment(ast::NodeAssignment {
+      identifier: left.clone().to_box(),
+      value: value.to_box(),
+      location: left.get_location(),
+      file: left.get_file(),
+    })
+    .into()
+  }
+  /// only identifiers, members and destructuring patterns can be assigned
+  fn check_assignment_target(
+    &self,
+    target: &ast::Node,
+    allow_pattern: bool,
+    start: &util::Location,
+    operator: &Token<TokenType>,
+  ) -> Result<(), NodeError> {
+    let message = match target {
+      ast::Node::Identifier(identifier) => {
+        if !self.is_const_binding(&identifier.name) {
+          return Ok(());
+        }
+        format!("No se puede reasignar la constante '{}'", identifier.name)
+      }
+      ast::Node::Member(member) if !member.optional => return Ok(()),
+      ast::Node::Array(array) if allow_pattern => {
+        for element in array.elements.clone() {
+          match element {
+            ast::NodeProperty::Indexable(value) => {
+              self.check_assignment_target(&value, true, &value.get_location(), operator)?
+            }
+            ast::NodeProperty::Iterable(value) => self.check_rest_target(&value, operator)?,
+            ast::NodeProperty::Property(_, value) | ast::NodeProperty::Dynamic(_, value) => {
+              self.check_assignment_target(&value, true, &value.get_location(), operator)?
+            }
+          }
+        }
+        return Ok(());
+      }
+      ast::Node::Object(object) if allow_pattern => {
+        for property in object.properties.clone() {
+          match property {
+            ast::NodeProperty::Property(_, value) | ast::NodeProperty::Dynamic(_, value) => {
+              self.check_assignment_target(&value, true, &value.get_location(), operator)?
+            }
+            ast::NodeProperty::Iterable(value) => self.check_rest_target(&value, operator)?,
+            ast::NodeProperty::Indexable(value) => {
+              return Err(self.target_error(&value.get_location(), operator, "Asignacion invalida"))
+            }
+          }
+        }
+        return Ok(());
+      }
+      _ if allow_pattern => {
+        "Solo se puede asignar a identificadores, miembros y patrones".to_string()
+      }
+      _ => "Solo se puede asignar a identificadores y miembros".to_string(),
+    };
+    Err(self.target_error(start, operator, &message))
+  }
+  /// the rest of a pattern (..resto) must be a name
+  fn check_rest_target(
+    &self,
+    target: &ast::Node,
+    operator: &Token<TokenType>,
+  ) -> Result<(), NodeError> {
+    let start = target.get_location();
+    if target.is_identifier() {
+      return self.check_assignment_target(target, false, &start, operator);
+    }
+    Err(self.target_error(
+      &start,
+      operator,
+      "El resto de un patron debe ser un identificador",
+    ))
+  }
+  fn target_error(
+    &self,
+    start: &util::Location,
+    operator: &Token<TokenType>,
+    message: &str,
+  ) -> NodeError {
+    let location = start.clone();
+    let line = self.source.lines().nth(location.start.line).unwrap_or("");
+    // the target is the source between its start and the operator
+    let end = if operator.location.start.line == location.start.line {
+      operator.location.start.column
+    } else {
+      line.chars().count()
+    };
+    let value = line
+      .chars()
+      .skip(location.start.column)
+      .take(end.saturating_sub(location.start.column))
+      .collect::<String>();
+    ast::NodeError {
+      message: message.to_string(),
+      location,
+      meta: format!("{}\0{}", line, value.trim_end()),
+    }
+  }
+  fn declare(&mut self, name: &str, is_const: bool) {
+    if let Some(scope) = self.bindings.last_mut() {
+      scope.push((name.to_string(), is_const));
+    }
+  }
+  fn declare_params(&mut self, params: &List<ast::NodeParameter>) {
+    let names = params.map(|param| param.name.clone());
+    self
+      .bindings
+      .push(names.into_iter().map(|name| (name, false)).collect());
+  }
+  fn is_const_binding(&self, name: &str) -> bool {
+    for scope in self.bindings.iter().rev() {
+      for (binding, is_const) in scope.iter().rev() {
+        if binding == name {
+          return *is_const;
+        }
+      }
+    }
+    false
+  }
+  fn parse_range_expr(&mut self, start: ast::Node) -> Result<ast::Node, NodeError> {
+    let range = self.parse_range(start)?;
+    self.parse_complex_expr(range)
+  }
+  fn parse_range(&mut self, start: ast::Node) -> Result<ast::Node, NodeError> {
+    self.eat(); // .
+    self.eat(); // .
+    let inclusive = self.match_join_token(TokenType::Operator(OperatorType::Equals));
+    let end = self.parse_math_lineal_expr()?;
+    let end = self.parse_back_unary_expr(end)?;
+    ast::Node::Range(ast::NodeRange {
+      start: start.clone().to_box(),
+      end: end.to_box(),
+      inclusive,
+      location: start.get_location(),
+      file: start.get_file(),
+    })
+    .into()
+  }
+  /// 'es' and 'en' bind tighter than the comparison and logical operators,
+  /// `x es Clase && k en obj` is `(x es Clase) && (k en obj)`
+  fn parse_relational_expr(&mut self, left: ast::Node) -> Result<ast::Node, NodeError> {
+    let operator = if self.match_token(TokenType::Keyword(KeywordsType::Is)) {
+      ast::NodeOperator::Is
     } else {
-      right.into()
+      self.eat(); // en
+      ast::NodeOperator::In
+    };
+    let right = self.parse_math_lineal_expr()?;
+    let mut right = self.parse_back_unary_expr(right)?;
+    // k en 0..10
+    if self.check_range() {
+      right = self.parse_range(right)?;
     }
+    ast::Node::Binary(ast::NodeBinary {
+      operator,
+      left: left.clone().to_box(),
+      right: right.to_box(),
+      location: left.get_location(),
+      file: left.get_file(),
+    })
+    .into()
   }
   fn parse_back_unary_expr(&mut self, left: ast::Node) -> Result<ast::Node, NodeError> {
     let token = self.at();
-    if self.match_token(TokenType::Operator(OperatorType::QuestionMark))
+    if self.check_optional_chain() {
+      self.parse_call_member_expr(left)
+    } else if self.match_token(TokenType::Operator(OperatorType::QuestionMark))
     // relative index: 1
     {
       // the expression isn't Nullish operator (??)
@@ -1713,19 +2981,51 @@ impl Parser {
   fn parse_call_member_expr(&mut self, object: ast::Node) -> Result<ast::Node, NodeError> {
     let member = self.parse_member_expr(object)?;
     if self.check_token(TokenType::Punctuation(PunctuationType::CircularBracketOpen)) {
-      return self.parse_call_expr(member);
+      return self.parse_call_expr(member, false);
     }
     member.into()
   }
-  fn parse_call_expr(&mut self, callee: ast::Node) -> Result<ast::Node, NodeError> {
+  /// members and calls of a value, without the operators after them
+  fn parse_postfix_expr(&mut self, object: ast::Node) -> Result<ast::Node, NodeError> {
+    let mut value = object;
+    loop {
+      value = self.parse_member_expr(value)?;
+      if !self.check_token(TokenType::Punctuation(PunctuationType::CircularBracketOpen)) {
+        return Ok(value);
+      }
+      value = self.parse_call(value, false)?;
+    }
+  }
+  fn parse_call_expr(&mut self, callee: ast::Node, optional: bool) -> Result<ast::Node, NodeError> {
+    let call_expr = self.parse_call(callee, optional)?;
+    self.parse_complex_expr(call_expr)
+  }
+  fn parse_call(&mut self, callee: ast::Node, optional: bool) -> Result<ast::Node, NodeError> {
     let token = self.eat();
     let mut args = List::new();
+    let mut has_named = false;
     while !(self.is_eof()
       || self.match_token(TokenType::Punctuation(
         PunctuationType::CircularBracketClose,
       )))
     {
-      let arg = self.parse_expr()?;
+      let arg_token = self.at();
+      let arg = self.parse_call_argument()?;
+      if let ast::NodeArgument::Named(_, _) = arg {
+        has_named = true;
+      } else if has_named {
+        let line = self
+          .source
+          .lines()
+          .nth(arg_token.location.start.line)
+          .unwrap();
+        return Err(ast::NodeError {
+          message: "No se puede usar un argumento posicional despues de un argumento con nombre"
+            .to_string(),
+          location: arg_token.location,
+          meta: format!("{}\0{}", line, arg_token.value),
+        });
+      }
       args.push(arg);
       if self.check_token(TokenType::Punctuation(
         PunctuationType::CircularBracketClose,
@@ -1741,19 +3041,71 @@ impl Parser {
         meta: format!("{}\0{}", line, comma.value),
       });
     }
-    let call_expr = ast::Node::Call(ast::NodeCall {
+    Ok(ast::Node::Call(ast::NodeCall {
       callee: callee.to_box(),
       arguments: args,
+      optional,
       location: token.location,
       file: token.meta,
-    });
-    self.parse_complex_expr(call_expr)
+    }))
+  }
+  fn parse_call_argument(&mut self) -> Result<ast::NodeArgument, ast::NodeError> {
+    let token = self.at();
+    if token.token_type == TokenType::Punctuation(PunctuationType::Dot) {
+      self.eat();
+      let dot = self.expect(
+        TokenType::Punctuation(PunctuationType::Dot),
+        "Se esperaba un punto",
+      );
+      if dot.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: dot.value,
+          location: dot.location,
+          meta: dot.meta,
+        });
+      }
+      let data = self.parse_expr()?;
+      return Ok(ast::NodeArgument::Iterable(data));
+    }
+    // f(nombre: valor), but not f(Clase::miembro)
+    if token.token_type == TokenType::Identifier
+      && self.next().token_type == TokenType::Punctuation(PunctuationType::DoubleDot)
+      && self.look(2).token_type != TokenType::Punctuation(PunctuationType::DoubleDot)
+    {
+      self.eat(); // nombre
+      self.eat(); // :
+      let value = self.parse_expr()?;
+      return Ok(ast::NodeArgument::Named(token.value, value));
+    }
+    let value = self.parse_expr()?;
+    Ok(ast::NodeArgument::Value(value))
   }
   fn parse_member_expr(&mut self, object: ast::Node) -> Result<ast::Node, NodeError> {
     let mut value = object;
     loop {
-      let object = self.check_token(TokenType::Punctuation(PunctuationType::Dot));
-      let instance = if self.match_token(TokenType::Punctuation(PunctuationType::DoubleDot)) {
+      // obj?.campo, obj?.[expr] and f?.()
+      let optional = if self.check_optional_chain() {
+        self.eat(); // ?
+        true
+      } else {
+        false
+      };
+      if optional
+        && self.next().token_type == TokenType::Punctuation(PunctuationType::CircularBracketOpen)
+      {
+        self.eat(); // .
+        value = self.parse_call(value, true)?;
+        continue;
+      }
+      if self.check_range() {
+        break;
+      }
+      let object = self.match_token(TokenType::Punctuation(PunctuationType::Dot));
+      // a single ':' belongs to a slice or a named argument
+      let instance = if self.check_token(TokenType::Punctuation(PunctuationType::DoubleDot))
+        && self.next().token_type == TokenType::Punctuation(PunctuationType::DoubleDot)
+      {
+        self.eat(); // :
         if self.match_join_token(TokenType::Punctuation(PunctuationType::DoubleDot)) {
           true
         } else {
@@ -1770,11 +3122,25 @@ impl Parser {
       if !(object || computed || instance) {
         break;
       }
-      let property = if computed {
-        self.parse_expr()
+      // lista[inicio:fin]
+      let is_slice_start =
+        computed && self.check_token(TokenType::Punctuation(PunctuationType::DoubleDot));
+      let property = if is_slice_start {
+        ast::Node::None
+      } else if computed {
+        self.parse_expr()?
+      } else {
+        self.parse_literal_member_expr()?
+      };
+      let slice = computed && self.match_token(TokenType::Punctuation(PunctuationType::DoubleDot));
+      let end = if slice
+        && !self.check_token(TokenType::Punctuation(
+          PunctuationType::QuadrateBracketClose,
+        )) {
+        Some(self.parse_expr()?.to_box())
       } else {
-        self.parse_literal_member_expr()
-      }?;
+        None
+      };
       if computed {
         let close = self.expect(
           TokenType::Punctuation(PunctuationType::QuadrateBracketClose),
@@ -1788,11 +3154,27 @@ impl Parser {
           });
         }
       }
+      if slice {
+        value = ast::Node::Slice(ast::NodeSlice {
+          object: value.clone().to_box(),
+          start: if is_slice_start {
+            None
+          } else {
+            Some(property.to_box())
+          },
+          end,
+          optional,
+          location: value.get_location(),
+          file: value.get_file(),
+        });
+        continue;
+      }
       value = ast::Node::Member(ast::NodeMember {
         object: value.clone().to_box(),
         member: property.to_box(),
         computed,
         instance,
+        optional,
         location: value.get_location(),
         file: value.get_file(),
       });
@@ -1821,6 +3203,7 @@ impl Parser {
   fn parse_literal_expr(&mut self) -> Result<Result<ast::Node, NodeError>, Token<TokenType>> {
     let token = self.at();
     match token.token_type {
+      TokenType::Identifier if self.check_typeof() => Ok(self.parse_keyword_unary_expr()),
       TokenType::Identifier => ast::Node::Identifier(ast::NodeIdentifier {
         name: self.eat().value,
         location: token.location,
@@ -1854,6 +3237,24 @@ impl Parser {
         file: token.meta,
       })
       .into(),
+      TokenType::Keyword(KeywordsType::True | KeywordsType::False) => {
+        ast::Node::Boolean(ast::NodeBoolean {
+          value: self.eat().token_type == TokenType::Keyword(KeywordsType::True),
+          location: token.location,
+          file: token.meta,
+        })
+        .into()
+      }
+      TokenType::Keyword(KeywordsType::Null) => ast::Node::Null(ast::NodeNull {
+        location: self.eat().location,
+        file: token.meta,
+      })
+      .into(),
+      TokenType::Keyword(KeywordsType::Undefined) => ast::Node::Undefined(ast::NodeNull {
+        location: self.eat().location,
+        file: token.meta,
+      })
+      .into(),
       TokenType::StringLiteral => ast::Node::String(ast::NodeString {
         value: List::from_vec(vec![ast::StringData::Str(self.eat().value)]),
         location: token.location,
@@ -1862,8 +3263,7 @@ impl Parser {
       .into(),
       TokenType::String => {
         self.eat();
-        let line = self.source.lines().nth(token.location.start.line).unwrap();
-        let node = string::complex_string(token, line);
+        let node = string::complex_string(self, token);
         match node {
           Err(e) => Ok(Err(e)),
           Ok(node) => ast::Node::String(node).into(),
@@ -1887,6 +3287,11 @@ impl Parser {
         return Ok(expr);
       }
       TokenType::Punctuation(PunctuationType::QuadrateBracketOpen) => Ok(self.parse_array_expr()),
+      TokenType::Operator(OperatorType::Plus | OperatorType::Minus)
+        if self.check_update_operator().is_some() =>
+      {
+        Ok(self.parse_prefix_update_expr())
+      }
       TokenType::Operator(
         OperatorType::Minus
         | OperatorType::Plus
@@ -1935,10 +3340,167 @@ impl Parser {
         | KeywordsType::Try
         | KeywordsType::Async
         | KeywordsType::Console,
-      ) => Ok(self.parse_keyword_value(false, false, false)),
+      ) => Ok(self.parse_keyword_value(false, false, self.in_async)),
+      TokenType::Keyword(KeywordsType::Yield) => Ok(self.parse_yield_expr()),
+      TokenType::Keyword(KeywordsType::Await) => Ok(self.parse_await_expr()),
+      TokenType::Keyword(KeywordsType::Delete) => Ok(self.parse_keyword_unary_expr()),
       _ => Err(token),
     }
   }
+  /// the operand of 'espera' is a value with its members and calls,
+  /// `espera f() + 1` is `(espera f()) + 1`
+  fn parse_await_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let token = self.eat(); // espera
+    if !self.in_async {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: format!(
+          "La palabra clave '{}' solo se puede utilizar en un contexto asíncrono",
+          KeywordsType::Await.as_str()
+        ),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    }
+    let operand = match self.parse_literal_expr() {
+      Ok(Ok(node)) => node,
+      Ok(Err(e)) => return Err(e),
+      Err(tok) => {
+        let line = self.source.lines().nth(tok.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Se esperaba una expresión despues de 'espera'".to_string(),
+          location: tok.location,
+          meta: format!("{}\0{}", line, tok.value),
+        });
+      }
+    };
+    let expression = self.parse_postfix_expr(operand)?.to_box();
+    ast::Node::Await(ast::NodeExpressionMedicator {
+      expression,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  /// `tipo` is an identifier unless it is followed by `de` and a value
+  fn check_typeof(&self) -> bool {
+    self.at().value == TYPEOF
+      && self.next().token_type == TokenType::Identifier
+      && self.next().value == TYPEOF_OF
+      && matches!(
+        self.look(2).token_type,
+        TokenType::Identifier
+          | TokenType::NumberLiteral
+          | TokenType::Number
+          | TokenType::Byte
+          | TokenType::String
+          | TokenType::StringLiteral
+          | TokenType::Keyword(_)
+          | TokenType::Punctuation(
+            PunctuationType::CircularBracketOpen
+              | PunctuationType::QuadrateBracketOpen
+              | PunctuationType::RegularBracketOpen
+          )
+      )
+  }
+  /// `tipo de valor` and `borra obj.campo`
+  fn parse_keyword_unary_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let token = self.eat();
+    let operator = if token.token_type == TokenType::Identifier {
+      self.eat(); // de
+      ast::NodeOperator::TypeOf
+    } else {
+      ast::NodeOperator::Delete
+    };
+    let operand = match self.parse_literal_expr() {
+      Ok(Ok(node)) => self.parse_postfix_expr(node)?,
+      Ok(Err(e)) => return Err(e),
+      Err(tok) => {
+        let line = self.source.lines().nth(tok.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: format!("Se esperaba una expresión despues de '{}'", token.value),
+          location: tok.location,
+          meta: format!("{}\0{}", line, tok.value),
+        });
+      }
+    };
+    if operator == ast::NodeOperator::Delete && !matches!(operand, ast::Node::Member(_)) {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: "Solo se pueden borrar propiedades de un objeto".to_string(),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    }
+    ast::Node::UnaryFront(ast::NodeUnary {
+      operator,
+      operand: operand.to_box(),
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_prefix_update_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let operator = self
+      .check_update_operator()
+      .unwrap_or(ast::NodeOperator::None);
+    let token = self.eat();
+    self.eat();
+    let start = self.at();
+    let operand = match self.parse_literal_expr() {
+      Ok(Ok(node)) => self.parse_postfix_expr(node)?,
+      Ok(Err(e)) => return Err(e),
+      Err(tok) => {
+        let line = self.source.lines().nth(tok.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: format!("Se esperaba una expresión despues de '{}'", operator),
+          location: tok.location,
+          meta: format!("{}\0{}", line, tok.value),
+        });
+      }
+    };
+    // the operand ends at the next token
+    let end = self.at();
+    self.check_assignment_target(&operand, false, &start.location, &end)?;
+    ast::Node::UnaryFront(ast::NodeUnary {
+      operator,
+      operand: operand.to_box(),
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
+  fn parse_yield_expr(&mut self) -> Result<ast::Node, NodeError> {
+    let token = self.eat(); // produce
+    if !self.in_generator {
+      let line = self.source.lines().nth(token.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: format!(
+          "La palabra clave '{}' solo se puede utilizar en una función generadora",
+          KeywordsType::Yield.as_str()
+        ),
+        location: token.location,
+        meta: format!("{}\0{}", line, token.value),
+      });
+    }
+    let value = if self.check_in_tokens(vec![
+      TokenType::Punctuation(PunctuationType::SemiColon),
+      TokenType::Punctuation(PunctuationType::Comma),
+      TokenType::Punctuation(PunctuationType::CircularBracketClose),
+      TokenType::Punctuation(PunctuationType::QuadrateBracketClose),
+      TokenType::Punctuation(PunctuationType::RegularBracketClose),
+    ]) {
+      None
+    } else {
+      Some(self.parse_expr()?.to_box())
+    };
+    ast::Node::Yield(ast::NodeReturn {
+      value,
+      location: token.location,
+      file: token.meta,
+    })
+    .into()
+  }
   fn parse_object_expr(&mut self) -> Result<ast::Node, NodeError> {
     let open_brace = self.eat();
     let mut properties = List::new();
@@ -1951,6 +3513,9 @@ impl Parser {
         return Err(property.err().unwrap());
       }
       let property = property.ok().unwrap();
+      if properties.len() == 0 && self.check_token(TokenType::Keyword(KeywordsType::Para)) {
+        return self.parse_comprehension_expr(property, open_brace, true);
+      }
       properties.push(property);
       if self.match_token(TokenType::Punctuation(PunctuationType::Comma)) {
         continue;
@@ -2108,6 +3673,9 @@ impl Parser {
         return Err(element.err().unwrap());
       }
       let property = element.ok().unwrap();
+      if elements.len() == 0 && self.check_token(TokenType::Keyword(KeywordsType::Para)) {
+        return self.parse_comprehension_expr(property, open_bracket, false);
+      }
       elements.push(property);
       if self.match_token(TokenType::Punctuation(PunctuationType::Comma)) {
         continue;
@@ -2132,33 +3700,131 @@ impl Parser {
     })
     .into()
   }
+  fn parse_comprehension_expr(
+    &mut self,
+    element: ast::NodeProperty,
+    open: Token<TokenType>,
+    is_object: bool,
+  ) -> Result<ast::Node, NodeError> {
+    let message = match (&element, is_object) {
+      (ast::NodeProperty::Iterable(_), _) => {
+        Some("No se puede expandir un valor en una comprension")
+      }
+      (ast::NodeProperty::Property(_, _), true) => {
+        Some("La clave de una comprension de objeto debe ser dinamica ([clave]: valor)")
+      }
+      _ => None,
+    };
+    if let Some(message) = message {
+      let line = self.source.lines().nth(open.location.start.line).unwrap();
+      return Err(ast::NodeError {
+        message: message.to_string(),
+        location: open.location,
+        meta: format!("{}\0{}", line, open.value),
+      });
+    }
+    let mut generators = List::new();
+    while self.check_token(TokenType::Keyword(KeywordsType::Para)) {
+      let token = self.eat(); // para
+      let identifier = self.expect(TokenType::Identifier, "Se esperaba un identificador");
+      if identifier.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: identifier.value,
+          location: identifier.location,
+          meta: identifier.meta,
+        });
+      }
+      let in_token = self.expect(
+        TokenType::Keyword(KeywordsType::In),
+        &format!(
+          "Se esperaba la palabra clave '{}'",
+          KeywordsType::In.as_str()
+        ),
+      );
+      if in_token.token_type == TokenType::Error {
+        return Err(ast::NodeError {
+          message: in_token.value,
+          location: in_token.location,
+          meta: in_token.meta,
+        });
+      }
+      let iterable = self.parse_expr()?;
+      let mut filters = List::new();
+      while self.match_token(TokenType::Keyword(KeywordsType::If)) {
+        filters.push(self.parse_expr()?);
+      }
+      generators.push(ast::NodeGenerator {
+        identifier: identifier.value,
+        iterable,
+        filters,
+        location: token.location,
+        file: token.meta,
+      });
+    }
+    let close = if is_object {
+      PunctuationType::RegularBracketClose
+    } else {
+      PunctuationType::QuadrateBracketClose
+    };
+    let close = self.expect(
+      TokenType::Punctuation(close),
+      "Se esperaba el cierre de la comprension",
+    );
+    if close.token_type == TokenType::Error {
+      return Err(ast::NodeError {
+        message: close.value,
+        location: close.location,
+        meta: close.meta,
+      });
+    }
+    let comprehension = ast::NodeComprehension {
+      element: Box::new(element),
+      generators,
+      location: open.location,
+      file: open.meta,
+    };
+    if is_object {
+      ast::Node::ObjectComprehension(comprehension).into()
+    } else {
+      ast::Node::ArrayComprehension(comprehension).into()
+    }
+  }
   fn parse_array_property(&mut self) -> Result<ast::NodeProperty, ast::NodeError> {
     let token = self.at();
     match token.token_type {
-      TokenType::Punctuation(p) => {
-        if p == PunctuationType::Dot {
-          self.eat();
-          let dot = self.expect(
-            TokenType::Punctuation(PunctuationType::Dot),
-            "Se esperaba un punto",
-          );
-          if dot.token_type == TokenType::Error {
-            return Err(ast::NodeError {
-              message: dot.value,
-              location: dot.location,
-              meta: dot.meta,
-            });
-          }
-          let data = self.parse_expr()?;
-          Ok(ast::NodeProperty::Iterable(data))
-        } else {
-          let line = self.source.lines().nth(token.location.start.line).unwrap();
+      TokenType::Punctuation(PunctuationType::Dot) => {
+        self.eat();
+        let dot = self.expect(
+          TokenType::Punctuation(PunctuationType::Dot),
+          "Se esperaba un punto",
+        );
+        if dot.token_type == TokenType::Error {
           return Err(ast::NodeError {
-            message: "Se esperaba un valor para la lista".to_string(),
-            location: token.location,
-            meta: format!("{}\0{}", line, token.value),
+            message: dot.value,
+            location: dot.location,
+            meta: dot.meta,
           });
         }
+        let data = self.parse_expr()?;
+        Ok(ast::NodeProperty::Iterable(data))
+      }
+      // an element can start with a bracket: [[1, 2], (a + b), {c: 3}],
+      // including the element of a comprehension: [[x, y] para x en a]
+      TokenType::Punctuation(
+        PunctuationType::QuadrateBracketOpen
+        | PunctuationType::CircularBracketOpen
+        | PunctuationType::RegularBracketOpen,
+      ) => {
+        let element = self.parse_expr()?;
+        Ok(ast::NodeProperty::Indexable(element))
+      }
+      TokenType::Punctuation(_) => {
+        let line = self.source.lines().nth(token.location.start.line).unwrap();
+        return Err(ast::NodeError {
+          message: "Se esperaba un valor para la lista".to_string(),
+          location: token.location,
+          meta: format!("{}\0{}", line, token.value),
+        });
       }
       _ => {
         let element = self.parse_expr()?;
@@ -26,22 +26,35 @@ pub enum Node {
   Number(NodeNumber),
   Object(NodeObject),
   Array(NodeArray),
+  ArrayComprehension(NodeComprehension),
+  ObjectComprehension(NodeComprehension),
   Byte(NodeByte),
+  Boolean(NodeBoolean),
+  Null(NodeNull),
+  Undefined(NodeNull),
   Identifier(NodeIdentifier),
+  Range(NodeRange),
 
   // Statements //
   VarDecl(NodeVarDecl),
   Name(NodeIdentifier),
+  Namespace(NodeNamespace),
   Assignment(NodeAssignment),
   Class(NodeClass),
+  Enum(NodeEnum),
+  Interface(NodeInterface),
   Function(NodeFunction),
   If(NodeIf),
   Import(NodeImport),
   Export(NodeValue),
   For(NodeFor),
+  ForEach(NodeForEach),
   While(NodeWhile),
   DoWhile(NodeWhile),
   Try(NodeTry),
+  Using(NodeUsing),
+  /// difiere expr; runs at the end of the enclosing block
+  Defer(NodeValue),
   Throw(NodeValue),
   Block(NodeBlock),
   Await(NodeExpressionMedicator),
@@ -53,8 +66,11 @@ pub enum Node {
   UnaryBack(NodeUnary),
   Binary(NodeBinary),
   Member(NodeMember),
+  Slice(NodeSlice),
   Call(NodeCall),
+  Pipeline(NodePipeline),
   Return(NodeReturn),
+  Yield(NodeReturn),
   LoopEdit(NodeLoopEdit),
 }
 impl Node {
@@ -83,29 +99,40 @@ impl Node {
     match self {
       Node::Await(node) | Node::Lazy(node) => node.location.clone(),
       Node::Byte(node) => node.location.clone(),
+      Node::Boolean(node) => node.location.clone(),
+      Node::Null(node) | Node::Undefined(node) => node.location.clone(),
       Node::Program(node) => node.location.clone(),
       Node::String(node) => node.location.clone(),
       Node::Number(node) => node.location.clone(),
       Node::Object(node) => node.location.clone(),
       Node::Array(node) => node.location.clone(),
+      Node::ArrayComprehension(node) | Node::ObjectComprehension(node) => node.location.clone(),
       Node::Identifier(node) => node.location.clone(),
+      Node::Range(node) => node.location.clone(),
       Node::VarDecl(node) => node.location.clone(),
       Node::Name(node) => node.location.clone(),
+      Node::Namespace(node) => node.location.clone(),
       Node::Assignment(node) => node.location.clone(),
       Node::Class(node) => node.location.clone(),
+      Node::Enum(node) => node.location.clone(),
+      Node::Interface(node) => node.location.clone(),
       Node::While(node) | Node::DoWhile(node) => node.location.clone(),
       Node::Try(node) => node.location.clone(),
+      Node::Using(node) => node.location.clone(),
       Node::Function(node) => node.location.clone(),
       Node::If(node) => node.location.clone(),
       Node::Import(node) => node.location.clone(),
-      Node::Export(node) | Node::Throw(node) => node.location.clone(),
+      Node::Export(node) | Node::Throw(node) | Node::Defer(node) => node.location.clone(),
       Node::UnaryFront(node) | Node::UnaryBack(node) => node.location.clone(),
       Node::Binary(node) => node.location.clone(),
       Node::Member(node) => node.location.clone(),
+      Node::Slice(node) => node.location.clone(),
       Node::Call(node) => node.location.clone(),
-      Node::Return(node) => node.location.clone(),
+      Node::Pipeline(node) => node.location.clone(),
+      Node::Return(node) | Node::Yield(node) => node.location.clone(),
       Node::LoopEdit(node) => node.location.clone(),
       Node::For(node) => node.location.clone(),
+      Node::ForEach(node) => node.location.clone(),
       Node::Block(node) => node.location.clone(),
       Node::Console(node) => match node {
         NodeConsole::Input { location, .. } => location,
@@ -125,29 +152,40 @@ impl Node {
     let file: &str = match self {
       Node::Await(node) | Node::Lazy(node) => &node.file,
       Node::Byte(node) => &node.file,
+      Node::Boolean(node) => &node.file,
+      Node::Null(node) | Node::Undefined(node) => &node.file,
       Node::Program(node) => &node.file,
       Node::String(node) => &node.file,
       Node::Number(node) => &node.file,
       Node::Object(node) => &node.file,
       Node::Array(node) => &node.file,
+      Node::ArrayComprehension(node) | Node::ObjectComprehension(node) => &node.file,
       Node::Identifier(node) => &node.file,
+      Node::Range(node) => &node.file,
       Node::VarDecl(node) => &node.file,
       Node::Name(node) => &node.file,
+      Node::Namespace(node) => &node.file,
       Node::Assignment(node) => &node.file,
       Node::Class(node) => &node.file,
+      Node::Enum(node) => &node.file,
+      Node::Interface(node) => &node.file,
       Node::While(node) | Node::DoWhile(node) => &node.file,
       Node::Try(node) => &node.file,
+      Node::Using(node) => &node.file,
       Node::Function(node) => &node.file,
       Node::If(node) => &node.file,
       Node::Import(node) => &node.file,
-      Node::Export(node) | Node::Throw(node) => &node.file,
+      Node::Export(node) | Node::Throw(node) | Node::Defer(node) => &node.file,
       Node::UnaryFront(node) | Node::UnaryBack(node) => &node.file,
       Node::Binary(node) => &node.file,
       Node::Member(node) => &node.file,
+      Node::Slice(node) => &node.file,
       Node::Call(node) => &node.file,
-      Node::Return(node) => &node.file,
+      Node::Pipeline(node) => &node.file,
+      Node::Return(node) | Node::Yield(node) => &node.file,
       Node::LoopEdit(node) => &node.file,
       Node::For(node) => &node.file,
+      Node::ForEach(node) => &node.file,
       Node::Console(_) | Node::Block(_) | Node::None => "none",
     };
     return file.to_string();
@@ -157,19 +195,30 @@ impl Node {
       Node::Lazy(_) => "Lazy",
       Node::Await(_) => "Await",
       Node::Byte(_) => "Byte",
+      Node::Boolean(_) => "Buleano",
+      Node::Null(_) => "Nulo",
+      Node::Undefined(_) => "Indefinido",
       Node::Program(_) => "Programa",
       Node::String(_) => "Cadena",
       Node::Number(_) => "Numero",
       Node::Object(_) => "Objeto",
       Node::Array(_) => "Lista",
+      Node::ArrayComprehension(_) => "Comprension de lista",
+      Node::ObjectComprehension(_) => "Comprension de objeto",
       Node::Identifier(_) => "Identificador",
+      Node::Range(_) => "Rango",
       Node::VarDecl(_) => "Variable",
       Node::Name(_) => "Nombre",
+      Node::Namespace(_) => "Espacio de nombres",
       Node::Assignment(_) => "Asignacion",
       Node::Class(_) => "Clase",
+      Node::Enum(_) => "Enumeracion",
+      Node::Interface(_) => "Interfaz",
       Node::While(_) => "Mientras",
       Node::DoWhile(_) => "Hacer",
       Node::Try(_) => "Intentar",
+      Node::Using(_) => "Usando",
+      Node::Defer(_) => "Diferir",
       Node::Function(_) => "Funcion",
       Node::If(_) => "Si",
       Node::Import(_) => "Importar",
@@ -178,10 +227,14 @@ impl Node {
       Node::UnaryBack(_) => "Operador Unario",
       Node::Binary(_) => "Operador Binario",
       Node::Member(_) => "Miembro",
+      Node::Slice(_) => "Porcion",
       Node::Call(_) => "Llamada",
+      Node::Pipeline(_) => "Tuberia",
       Node::Return(_) => "Retorno",
+      Node::Yield(_) => "Producir",
       Node::LoopEdit(_) => "Editor de bucle",
       Node::For(_) => "Para",
+      Node::ForEach(_) => "Para cada",
       Node::Block(_) => "Bloque",
       Node::None => "Nada",
       Node::Console(_) => "Consola",
@@ -207,12 +260,23 @@ impl std::fmt::Display for Node {
       Node::Lazy(node) => format!("NodeLazy:\n  {}", node.expression),
       Node::Await(node) => format!("NodeAwait:\n  {}", node.expression),
       Node::Byte(node) => format!("NodeByte: {}", node.value),
+      Node::Boolean(node) => format!(
+        "NodeBoolean: {}",
+        if node.value {
+          KeywordsType::True.as_str()
+        } else {
+          KeywordsType::False.as_str()
+        }
+      ),
+      Node::Null(_) => "NodeNull".to_string(),
+      Node::Undefined(_) => "NodeUndefined".to_string(),
       Node::Block(node) => node.body.to_string(),
       Node::Program(node) => format!("NodeProgram:\n{}", data_format(node.body.to_string())),
       Node::String(node) => {
         let str_value = node.value.map(|data| match data {
           StringData::Str(str) => format!("\"{}\"", str).replace("\n", "\\n"),
-          StringData::Id(id) => id.to_string(),
+          StringData::Expr(node, Some(format)) => format!("{{{}:{}}}", node, format),
+          StringData::Expr(node, None) => format!("{{{}}}", node),
         });
         format!("NodeString: {}", str_value)
       }
@@ -242,6 +306,39 @@ impl std::fmt::Display for Node {
         });
         format!("NodeArray: [\n{}\n]", data_format(str_elements.join(",\n")))
       }
+      Node::ArrayComprehension(node) | Node::ObjectComprehension(node) => {
+        let str_element = match node.element.as_ref() {
+          NodeProperty::Property(name, value) => format!("{}:\n  {}", name, value),
+          NodeProperty::Iterable(object) => format!("...({})", object),
+          NodeProperty::Dynamic(name, value) => format!("[{}]:\n  {}", name, value),
+          NodeProperty::Indexable(value) => value.to_string(),
+        };
+        let str_generators = node
+          .generators
+          .map(|generator| {
+            let str_filters = generator
+              .filters
+              .map(|filter| format!("\nsi\n{}", data_format(filter.to_string())))
+              .join("");
+            format!(
+              "para {} en\n{}{}",
+              generator.identifier,
+              data_format(generator.iterable.to_string()),
+              str_filters
+            )
+          })
+          .join("\n");
+        format!(
+          "{}:\n{}\n{}",
+          if let Node::ArrayComprehension(_) = self {
+            "NodeArrayComprehension"
+          } else {
+            "NodeObjectComprehension"
+          },
+          data_format(str_element),
+          data_format(str_generators)
+        )
+      }
       Node::Identifier(node) => format!("NodeIdentifier: {}", node.name),
       Node::VarDecl(node) => {
         let keyword = if node.is_const {
@@ -250,14 +347,23 @@ impl std::fmt::Display for Node {
           KeywordsType::Define
         };
         let keyword = keyword.as_str();
-        match &node.value {
-          Some(value) => format!(
-            "NodeVarDecl: {keyword} {}\n{}",
-            node.name,
-            data_format(value.to_string())
-          ),
-          None => format!("NodeVarDecl: {keyword} {}", node.name),
-        }
+        let str_declarators = node
+          .declarators
+          .map(|declarator| match &declarator.value {
+            Some(value) => format!(
+              "{}{}\n{}",
+              declarator.name,
+              str_type(&declarator.type_annotation),
+              data_format(value.to_string())
+            ),
+            None => format!(
+              "{}{}",
+              declarator.name,
+              str_type(&declarator.type_annotation)
+            ),
+          })
+          .join("\n");
+        format!("NodeVarDecl: {keyword}\n{}", data_format(str_declarators))
       }
       Node::Assignment(node) => format!(
         "NodeAssignment: {}\n{}",
@@ -265,38 +371,99 @@ impl std::fmt::Display for Node {
         data_format(node.value.to_string())
       ),
       Node::Name(node) => format!("NodeName: {}", node.name),
+      Node::Namespace(node) => format!(
+        "NodeNamespace: {}\n{}",
+        node.name,
+        data_format(node.body.join("\n"))
+      ),
       Node::Class(node) => {
         let str_body = node.body.map(|p| {
-          let is_static = p.meta & 1 << 0 != 0;
-          let str_static = format!("static: {is_static}");
-          let is_const = p.meta & 1 << 1 != 0;
-          let str_const = format!("const: {is_const}");
-          let is_public = p.meta & 1 << 2 != 0;
-          let str_public = format!("public: {is_public}");
-          let str_info = format!("{str_static}\n{str_const}\n{str_public}");
-          let str_info = format!("{}:\n{}", p.name, data_format(str_info));
-          match &p.value {
-            Some(value) => {
-              format!("{str_info}\n{}", data_format(value.to_string()))
+          let str_static = format!("static: {}", p.is_static);
+          let str_const = format!("const: {}", p.is_const);
+          let str_public = format!("public: {}", p.is_public);
+          let str_async = format!("async: {}", p.is_async);
+          let str_info = format!("{str_static}\n{str_const}\n{str_public}\n{str_async}");
+          let (kind, value) = match &p.kind {
+            NodeClassPropertyKind::Field(value) => ("campo", value.as_ref().map(|v| v.to_string())),
+            NodeClassPropertyKind::Method(function) => ("metodo", Some(function.body.join("\n"))),
+            NodeClassPropertyKind::Getter(function) => ("obtener", Some(function.body.join("\n"))),
+            NodeClassPropertyKind::Setter(function) => {
+              ("establecer", Some(function.body.join("\n")))
+            }
+            NodeClassPropertyKind::Constructor(function) => {
+              ("constructor", Some(function.body.join("\n")))
+            }
+            NodeClassPropertyKind::StaticBlock(block) => {
+              ("bloque estatico", Some(block.join("\n")))
             }
+            NodeClassPropertyKind::Operator(_, function) => {
+              ("operador", Some(function.body.join("\n")))
+            }
+          };
+          let str_info = format!(
+            "{}{}{} ({kind}):\n{}",
+            str_attributes(&p.attributes),
+            p.name,
+            str_type(&p.type_annotation),
+            data_format(str_info)
+          );
+          match value {
+            Some(value) => format!("{str_info}\n{}", data_format(value)),
             None => str_info,
           }
         });
+        let str_implements = if node.implements.len() > 0 {
+          let names = node.implements.map(|interface| interface.name.clone());
+          format!(
+            " {} {}",
+            KeywordsType::Implements.as_str(),
+            names.join(", ")
+          )
+        } else {
+          "".to_string()
+        };
         format!(
-          "NodeClass: {}\n{}",
+          "{}NodeClass: {}{}\n{}",
+          str_attributes(&node.attributes),
           node.name,
+          str_implements,
           data_format(str_body.join("\n"))
         )
       }
+      Node::Interface(node) => {
+        let str_methods = node
+          .methods
+          .map(|method| {
+            let str_params = method
+              .params
+              .map(|arg| format!("{}{}", arg.name, str_type(&arg.type_annotation)))
+              .join(", ");
+            let str_signature = format!(
+              "{}{}({}){}",
+              if method.is_async { "asinc " } else { "" },
+              method.name,
+              str_params,
+              str_type(&method.return_type)
+            );
+            match &method.body {
+              Some(body) => format!("{}:\n{}", str_signature, data_format(body.join("\n"))),
+              None => str_signature,
+            }
+          })
+          .join("\n");
+        format!("NodeInterface: {}\n{}", node.name, data_format(str_methods))
+      }
       Node::While(node) | Node::DoWhile(node) => format!(
-        "NodeWhile:\n{}\n  <==>\n{}",
+        "NodeWhile{}:\n{}\n  <==>\n{}",
+        str_label(&node.label),
         data_format(node.condition.to_string()),
         data_format(node.body.join("\n"))
       ),
       Node::Try(node) => {
-        let str_catch = match &node.catch {
-          Some(catch) => format!("NodeTryCatch: {}:\n{}", catch.0, catch.1),
-          None => "No Catch".to_string(),
+        let str_catch = if node.catch.len() == 0 {
+          "No Catch".to_string()
+        } else {
+          node.catch.map(|catch| catch.to_string()).join("\n")
         };
         let str_finally = match &node.finally {
           Some(finally) => format!("NodeTryFinally:\n{}", finally),
@@ -309,27 +476,66 @@ impl std::fmt::Display for Node {
           data_format(str_finally)
         )
       }
+      Node::Enum(node) => {
+        let str_variants = node
+          .variants
+          .map(|variant| {
+            let str_data = if variant.data.len() > 0 {
+              let str_params = variant
+                .data
+                .map(|arg| format!("{}{}", arg.name, str_type(&arg.type_annotation)))
+                .join(", ");
+              format!("({})", str_params)
+            } else {
+              "".to_string()
+            };
+            match &variant.value {
+              Some(value) => format!(
+                "{}{}:\n{}",
+                variant.name,
+                str_data,
+                data_format(value.to_string())
+              ),
+              None => format!("{}{}", variant.name, str_data),
+            }
+          })
+          .join("\n");
+        format!("NodeEnum: {}\n{}", node.name, data_format(str_variants))
+      }
       Node::Function(node) => {
         let str_params = node
           .params
-          .map(|arg| format!("{}", Node::Identifier(arg.clone())))
+          .map(|arg| format!("{}{}", arg.name, str_type(&arg.type_annotation)))
           .join(", ");
         format!(
-          "NodeFunction: {} ({})\n{}",
+          "{}NodeFunction: {} ({}){}\n{}",
+          str_attributes(&node.attributes),
           node.name,
           str_params,
+          str_type(&node.return_type),
           data_format(node.body.join("\n"))
         )
       }
       Node::If(node) => {
+        let str_else_if = node
+          .else_if
+          .map(|branch| {
+            format!(
+              "\n  <==> si\n{}\n  <==>\n{}",
+              data_format(branch.condition.to_string()),
+              data_format(branch.body.join("\n"))
+            )
+          })
+          .join("");
         let str_else_body = match &node.else_body {
           Some(else_body) => format!("\n  <==>\n{}", data_format(else_body.join("\n"))),
           None => "".to_string(),
         };
         format!(
-          "NodeIf:\n{}\n  <==>\n{}{}",
+          "NodeIf:\n{}\n  <==>\n{}{}{}",
           data_format(node.condition.to_string()),
           data_format(node.body.join("\n")),
+          str_else_if,
           str_else_body
         )
       }
@@ -337,16 +543,43 @@ impl std::fmt::Display for Node {
         Some(name) => format!("NodeImport: {} como {}", node.path, name),
         None => format!("NodeImport: {}", node.path),
       },
+      Node::Defer(node) => format!("NodeDefer:\n{}", data_format(node.value.to_string())),
+      Node::Using(node) => format!(
+        "NodeUsing:\n{}\n  <==>\n{}",
+        data_format(node.resource.to_string()),
+        data_format(node.body.join("\n"))
+      ),
       Node::Export(node) | Node::Throw(node) => {
         format!("NodeValue: \n{}", data_format(node.value.to_string()))
       }
       Node::For(node) => format!(
-        "NodeFor: \n{}\n{}\n{}\n  <==>\n{}",
+        "NodeFor{}: \n{}\n{}\n{}\n  <==>\n{}",
+        str_label(&node.label),
         data_format(node.init.to_string()),
         data_format(node.condition.to_string()),
         data_format(node.update.to_string()),
         data_format(node.body.join("\n"))
       ),
+      Node::ForEach(node) => format!(
+        "NodeForEach{}: {} en\n{}\n  <==>\n{}",
+        str_label(&node.label),
+        node.identifier,
+        data_format(node.iterable.to_string()),
+        data_format(node.body.join("\n"))
+      ),
+      Node::Range(node) => format!(
+        "NodeRange{}:\n{}\n{}",
+        if node.inclusive { " (inclusivo)" } else { "" },
+        data_format(node.start.to_string()),
+        data_format(node.end.to_string())
+      ),
+      Node::Slice(node) => format!(
+        "NodeSlice{}:\n{}\n{}\n{}",
+        if node.optional { " (opcional)" } else { "" },
+        data_format(node.object.to_string()),
+        data_format(str_slice_bound(&node.start)),
+        data_format(str_slice_bound(&node.end))
+      ),
       Node::UnaryFront(node) | Node::UnaryBack(node) => format!(
         "NodeUnary: \"{:?}\" para {{\n{}\n}}",
         node.operator,
@@ -358,18 +591,29 @@ impl std::fmt::Display for Node {
         node.operator,
         data_format(node.right.to_string())
       ),
+      Node::Pipeline(node) => format!(
+        "NodePipeline:\n{}\n |>\n{}",
+        data_format(node.value.to_string()),
+        data_format(node.stage.to_string())
+      ),
       Node::Member(node) => format!(
-        "NodeMember:\n{}\n{}",
+        "NodeMember{}:\n{}\n{}",
+        if node.optional { " (opcional)" } else { "" },
         data_format(node.object.to_string()),
         data_format(node.member.to_string())
       ),
       Node::Call(node) => {
         let str_arguments = node
           .arguments
-          .map(|argument| format!("  {}", argument))
+          .map(|argument| match argument {
+            NodeArgument::Value(value) => format!("  {}", value),
+            NodeArgument::Iterable(value) => format!("  ...({})", value),
+            NodeArgument::Named(name, value) => format!("  {}:\n  {}", name, value),
+          })
           .join("\n");
         format!(
-          "NodeCall:\n{}\n  ({})",
+          "NodeCall{}:\n{}\n  ({})",
+          if node.optional { " (opcional)" } else { "" },
           data_format(node.callee.to_string()),
           data_format(str_arguments)
         )
@@ -378,12 +622,17 @@ impl std::fmt::Display for Node {
         Some(value) => format!("NodeReturn:\n{}", data_format(value.to_string())),
         None => "NodeReturn".to_string(),
       },
+      Node::Yield(node) => match &node.value {
+        Some(value) => format!("NodeYield:\n{}", data_format(value.to_string())),
+        None => "NodeYield".to_string(),
+      },
       Node::LoopEdit(node) => format!(
-        "NodeLoopEdit: {}",
+        "NodeLoopEdit: {}{}",
         match node.action {
           NodeLoopEditType::Break => "break",
           NodeLoopEditType::Continue => "continue",
-        }
+        },
+        str_label(&node.label)
       ),
       Node::None => "NodeNone".to_string(),
       Node::Console(NodeConsole::Input { identifier, .. }) => format!("NodeConsole: Input ({})", identifier),
@@ -436,10 +685,57 @@ impl std::fmt::Display for NodeOperator {
       Self::Not => "!",
       Self::NotEqual => "!=",
       Self::Assign => "=",
+      Self::Is => KeywordsType::Is.as_str(),
+      Self::In => KeywordsType::In.as_str(),
+      Self::TypeOf => "tipo de",
+      Self::Delete => KeywordsType::Delete.as_str(),
+      Self::Increment => "++",
+      Self::Decrement => "--",
     };
     write!(f, "{}", str)
   }
 }
+impl std::fmt::Display for NodeType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.arguments.len() == 0 {
+      return write!(f, "{}", self.name);
+    }
+    let str_arguments = self.arguments.map(|arg| arg.to_string()).join(", ");
+    write!(f, "{}<{}>", self.name, str_arguments)
+  }
+}
+impl std::fmt::Display for NodeAttribute {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.arguments.len() == 0 {
+      return write!(f, "@{}", self.name);
+    }
+    let str_arguments = self.arguments.map(|arg| arg.to_string()).join(", ");
+    write!(f, "@{}({})", self.name, str_arguments)
+  }
+}
+fn str_attributes(attributes: &List<NodeAttribute>) -> String {
+  attributes
+    .map(|attribute| format!("{}\n", attribute))
+    .join("")
+}
+fn str_type(type_annotation: &Option<NodeType>) -> String {
+  match type_annotation {
+    Some(type_annotation) => format!(": {}", type_annotation),
+    None => "".to_string(),
+  }
+}
+fn str_slice_bound(bound: &Option<BNode>) -> String {
+  match bound {
+    Some(bound) => bound.to_string(),
+    None => "_".to_string(),
+  }
+}
+fn str_label(label: &Option<String>) -> String {
+  match label {
+    Some(label) => format!(" ({})", label),
+    None => "".to_string(),
+  }
+}
 fn data_format(data: String) -> String {
   data
     .split("\n")
@@ -465,7 +761,11 @@ pub enum NodeConsole {
 }
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeBlock {
+  /// the statements in source order
   pub body: List<Node>,
+  /// names of the functions declared in the block (exported or not),
+  /// they can be used before their declaration
+  pub hoisted: List<String>,
   pub in_function: bool,
   pub in_loop: bool,
   pub location: util::Location,
@@ -477,6 +777,27 @@ impl NodeBlock {
   pub fn to_node(self) -> Node {
     Node::Block(self)
   }
+  /// the function declarations of the block, in source order
+  pub fn hoisted_functions(&self) -> List<Node> {
+    let functions = self
+      .body
+      .clone()
+      .into_iter()
+      .filter(|node| hoisted_name(node).is_some())
+      .collect::<Vec<_>>();
+    List::from_vec(functions)
+  }
+}
+/// the name of a function declaration, also when it is exported
+pub fn hoisted_name(node: &Node) -> Option<String> {
+  match node {
+    Node::Function(function) => Some(function.name.clone()),
+    Node::Export(export) => match export.value.as_ref() {
+      Node::Function(function) => Some(function.name.clone()),
+      _ => None,
+    },
+    _ => None,
+  }
 }
 impl IntoIterator for NodeBlock {
   type Item = Node;
@@ -495,7 +816,8 @@ pub struct NodeProgram {
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum StringData {
   Str(String),
-  Id(String),
+  /// {expresion} or {expresion:formato}
+  Expr(BNode, Option<String>),
 }
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeString {
@@ -517,6 +839,17 @@ pub struct NodeByte {
   pub file: String,
 }
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeBoolean {
+  pub value: bool,
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeNull {
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum NodeProperty {
   Property(String, Node),
   Dynamic(Node, Node),
@@ -535,11 +868,36 @@ pub struct NodeArray {
   pub location: util::Location,
   pub file: String,
 }
+/// [valor para x en lista si x > 0] or {[clave]: valor para x en lista}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeComprehension {
+  pub element: Box<NodeProperty>,
+  pub generators: List<NodeGenerator>,
+  pub location: util::Location,
+  pub file: String,
+}
+/// para x en lista si x > 0
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeGenerator {
+  pub identifier: String,
+  pub iterable: Node,
+  pub filters: List<Node>,
+  pub location: util::Location,
+  pub file: String,
+}
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeVarDecl {
+  pub declarators: List<NodeDeclarator>,
+  pub is_const: bool,
+  pub location: util::Location,
+  pub file: String,
+}
+/// def a = 1, b;
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeDeclarator {
   pub name: String,
   pub value: Option<BNode>,
-  pub is_const: bool,
+  pub type_annotation: Option<NodeType>,
   pub location: util::Location,
   pub file: String,
 }
@@ -656,6 +1014,18 @@ pub enum NodeOperator {
   Assign,
   /// ==
   Equal,
+  /// es
+  Is,
+  /// en
+  In,
+  /// tipo de
+  TypeOf,
+  /// borra
+  Delete,
+  /// ++
+  Increment,
+  /// --
+  Decrement,
 }
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeBinary {
@@ -665,6 +1035,14 @@ pub struct NodeBinary {
   pub location: util::Location,
   pub file: String,
 }
+/// valor |> etapa, `x |> f(2)` calls `f(x, 2)`
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodePipeline {
+  pub value: BNode,
+  pub stage: BNode,
+  pub location: util::Location,
+  pub file: String,
+}
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeAssignment {
   pub identifier: BNode,
@@ -678,13 +1056,46 @@ pub struct NodeMember {
   pub member: BNode,
   pub instance: bool,
   pub computed: bool,
+  /// obj?.campo
+  pub optional: bool,
   pub location: util::Location,
   pub file: String,
 }
+/// lista[inicio:fin], the bounds are optional
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeSlice {
+  pub object: BNode,
+  pub start: Option<BNode>,
+  pub end: Option<BNode>,
+  /// lista?.[inicio:fin]
+  pub optional: bool,
+  pub location: util::Location,
+  pub file: String,
+}
+/// inicio..fin or inicio..=fin
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeRange {
+  pub start: BNode,
+  pub end: BNode,
+  pub inclusive: bool,
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NodeArgument {
+  /// f(valor)
+  Value(Node),
+  /// f(..lista)
+  Iterable(Node),
+  /// f(nombre: valor)
+  Named(String, Node),
+}
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeCall {
   pub callee: BNode,
-  pub arguments: List<Node>,
+  pub arguments: List<NodeArgument>,
+  /// f?.()
+  pub optional: bool,
   pub location: util::Location,
   pub file: String,
 }
@@ -692,6 +1103,7 @@ pub struct NodeCall {
 pub struct NodeWhile {
   pub condition: BNode,
   pub body: NodeBlock,
+  pub label: Option<String>,
   pub location: util::Location,
   pub file: String,
 }
@@ -699,16 +1111,51 @@ pub struct NodeWhile {
 pub struct NodeIf {
   pub condition: BNode,
   pub body: NodeBlock,
+  /// ent si condicion {}, in source order
+  pub else_if: List<NodeElseIf>,
+  /// ent {}, kept even when empty
   pub else_body: Option<NodeBlock>,
   pub location: util::Location,
   pub file: String,
 }
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeElseIf {
+  pub condition: BNode,
+  pub body: NodeBlock,
+  pub location: util::Location,
+  pub file: String,
+}
 
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeType {
+  pub name: String,
+  /// Lista<Numero>
+  pub arguments: List<NodeType>,
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeParameter {
+  pub name: String,
+  pub type_annotation: Option<NodeType>,
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeAttribute {
+  pub name: String,
+  pub arguments: List<Node>,
+  pub location: util::Location,
+  pub file: String,
+}
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeFunction {
   pub is_async: bool,
+  pub is_generator: bool,
+  pub attributes: List<NodeAttribute>,
   pub name: String,
-  pub params: List<NodeIdentifier>,
+  pub params: List<NodeParameter>,
+  pub return_type: Option<NodeType>,
   pub body: NodeBlock,
   pub location: util::Location,
   pub file: String,
@@ -727,34 +1174,147 @@ pub enum NodeLoopEditType {
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeLoopEdit {
   pub action: NodeLoopEditType,
+  /// rom etiqueta;
+  pub label: Option<String>,
   pub location: util::Location,
   pub file: String,
 }
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeTry {
   pub body: NodeBlock,
-  pub catch: Option<(String, NodeBlock)>,
+  pub catch: List<NodeCatch>,
   pub finally: Option<NodeBlock>,
   pub location: util::Location,
   pub file: String,
 }
+/// captura (error: ErrorDeTipo) si condicion {}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeCatch {
+  /// captura {} has no binding
+  pub name: Option<String>,
+  /// only errors of this class are caught
+  pub class: Option<NodeType>,
+  pub guard: Option<BNode>,
+  pub body: NodeBlock,
+  pub location: util::Location,
+  pub file: String,
+}
+impl NodeCatch {
+  /// a clause without class nor guard catches every error
+  pub fn is_catch_all(&self) -> bool {
+    self.class.is_none() && self.guard.is_none()
+  }
+}
+impl std::fmt::Display for NodeCatch {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let name = match &self.name {
+      Some(name) => name.clone(),
+      None => "_".to_string(),
+    };
+    let class = match &self.class {
+      Some(class) => format!(": {}", class),
+      None => "".to_string(),
+    };
+    let guard = match &self.guard {
+      Some(guard) => format!("\n  si\n{}", data_format(data_format(guard.to_string()))),
+      None => "".to_string(),
+    };
+    write!(f, "NodeTryCatch: {}{}{}\n{}", name, class, guard, self.body)
+  }
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NodeClassPropertyKind {
+  /// nombre = valor;
+  Field(Option<BNode>),
+  /// nombre() {}
+  Method(NodeFunction),
+  /// obtener nombre() {}
+  Getter(NodeFunction),
+  /// establecer nombre(valor) {}
+  Setter(NodeFunction),
+  /// constructor() {}
+  Constructor(NodeFunction),
+  /// est {}
+  StaticBlock(NodeBlock),
+  /// operador + (otro) {}
+  Operator(NodeOperator, NodeFunction),
+}
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeClassProperty {
   pub name: String,
-  pub value: Option<BNode>,
-  /** bits
-  1: is_static
-  2: is_public */
-  pub meta: u8,
+  pub kind: NodeClassPropertyKind,
+  pub attributes: List<NodeAttribute>,
+  /// only fields, methods keep it in the function
+  pub type_annotation: Option<NodeType>,
+  pub is_static: bool,
+  pub is_public: bool,
+  pub is_const: bool,
+  pub is_async: bool,
+  pub location: util::Location,
+  pub file: String,
 }
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeClass {
   pub name: String,
+  pub attributes: List<NodeAttribute>,
   pub extend_of: Option<NodeIdentifier>,
+  pub implements: List<NodeIdentifier>,
   pub body: List<NodeClassProperty>,
   pub location: util::Location,
   pub file: String,
 }
+/// usando (def archivo = abrir(ruta)) {}, the resource is released at the end of the block
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeUsing {
+  /// a declaration or an expression
+  pub resource: BNode,
+  pub body: NodeBlock,
+  pub location: util::Location,
+  pub file: String,
+}
+/// nombre X { ... }, the members are used with X::miembro
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeNamespace {
+  pub name: String,
+  pub body: NodeBlock,
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeInterface {
+  pub name: String,
+  pub methods: List<NodeInterfaceMethod>,
+  pub location: util::Location,
+  pub file: String,
+}
+/// the signature of a method, the body is the default implementation
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeInterfaceMethod {
+  pub name: String,
+  pub is_async: bool,
+  pub params: List<NodeParameter>,
+  pub return_type: Option<NodeType>,
+  pub body: Option<NodeBlock>,
+  pub location: util::Location,
+  pub file: String,
+}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeEnum {
+  pub name: String,
+  pub variants: List<NodeEnumVariant>,
+  pub location: util::Location,
+  pub file: String,
+}
+/// Rojo, Verde = 5 or Punto(x, y)
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeEnumVariant {
+  pub name: String,
+  pub value: Option<BNode>,
+  /// the associated data of the variant
+  pub data: List<NodeParameter>,
+  pub location: util::Location,
+  pub file: String,
+}
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NodeImport {
   pub path: String,
@@ -775,6 +1335,17 @@ pub struct NodeFor {
   pub condition: BNode,
   pub update: BNode,
   pub body: NodeBlock,
+  pub label: Option<String>,
+  pub location: util::Location,
+  pub file: String,
+}
+/// para (valor en iterable) {}
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NodeForEach {
+  pub identifier: String,
+  pub iterable: BNode,
+  pub body: NodeBlock,
+  pub label: Option<String>,
   pub location: util::Location,
   pub file: String,
 }
@@ -4,80 +4,133 @@ use crate::{
 };
 use util::{List, Token};
 
-fn is_alpha(c: char) -> bool {
-  c.is_alphabetic() || c == '_' || c == '$' || c.is_numeric()
+use super::Parser;
+
+/// column in the source line of every character of the string value,
+/// the escape sequences use more than one column in the source
+fn source_columns(line: &str, start: usize, length: usize) -> Vec<usize> {
+  let source = line.chars().collect::<Vec<char>>();
+  let mut columns = Vec::new();
+  let mut column = start + 1; // the quote character
+  while columns.len() < length {
+    columns.push(column);
+    column += match source.get(column) {
+      Some('\\') => match source.get(column + 1) {
+        Some('x') => 4,
+        Some('u') => 6,
+        _ => 2,
+      },
+      _ => 1,
+    };
+  }
+  columns
 }
-pub fn complex_string(token_string: Token<TokenType>, line: &str) -> Result<NodeString, NodeError> {
-  let string = token_string.value;
+fn string_error(token: &Token<TokenType>, line: &str, column: usize, message: &str) -> NodeError {
+  let mut location = token.location.clone();
+  location.start.column = column;
+  NodeError {
+    message: message.to_string(),
+    location,
+    meta: format!("{}\0{}", line, token.value),
+  }
+}
+pub fn complex_string(
+  parser: &Parser,
+  token_string: Token<TokenType>,
+) -> Result<NodeString, NodeError> {
+  let line = parser
+    .source
+    .lines()
+    .nth(token_string.location.start.line)
+    .unwrap_or("");
+  let string = token_string.value.chars().collect::<Vec<char>>();
+  let columns = source_columns(line, token_string.location.start.column, string.len());
   let mut result = List::new();
   let mut current = String::new();
-  let mut is_id = false;
   let mut i = 0;
   while i < string.len() {
-    let c = string.chars().nth(i);
-    if c.is_none() {
-      break;
-    }
-    let c = c.unwrap();
-    i += 1;
-    if c == '}' && is_id == false {
-      let nc = string.chars().nth(i);
-      i += 1;
-      if nc == None {
-        return Err(NodeError {
-          message: "No se encontro la apertura de el identificador".to_string(),
-          location: token_string.location,
-          meta: format!("{}\0{}", line, string),
-        });
-      }
-      let nc = nc.unwrap();
-      if nc == '}' {
+    let c = string[i];
+    if c == '}' {
+      if string.get(i + 1) == Some(&'}') {
         current.push('}');
+        i += 2;
         continue;
       }
+      return Err(string_error(
+        &token_string,
+        line,
+        columns[i],
+        "No se encontro la apertura de la interpolacion",
+      ));
     }
-    if c != '{' && is_id == false {
+    if c != '{' {
       current.push(c);
+      i += 1;
       continue;
     }
-    if is_id {
-      if c == '}' {
-        result.push(StringData::Id(current.clone()));
-        current.clear();
-        is_id = false;
+    if string.get(i + 1) == Some(&'{') {
+      current.push('{');
+      i += 2;
+      continue;
+    }
+    // {expresion} or {expresion:formato}
+    let start = i + 1;
+    let mut end = start;
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut format_index: Option<usize> = None;
+    while end < string.len() {
+      let ch = string[end];
+      if let Some(q) = quote {
+        if ch == q {
+          quote = None;
+        }
+        end += 1;
         continue;
       }
-      if is_alpha(c) {
-        current.push(c);
-        continue;
+      if ch == '}' && depth == 0 {
+        break;
+      }
+      if format_index.is_none() {
+        match ch {
+          '\'' | '"' => quote = Some(ch),
+          '(' | '[' | '{' => depth += 1,
+          ')' | ']' | '}' => depth -= 1,
+          // Clase::miembro is not a format
+          ':' if string.get(end + 1) == Some(&':') => end += 1,
+          ':' if depth == 0 => format_index = Some(end),
+          _ => {}
+        }
       }
+      end += 1;
     }
-    let nc = string.chars().nth(i);
-    i += 1;
-    if nc == None {
-      return Err(NodeError {
-        message: "Se esperaba un caracter literal".to_string(),
-        location: token_string.location,
-        meta: format!("{}\0{}", line, string),
-      });
+    if end >= string.len() {
+      return Err(string_error(
+        &token_string,
+        line,
+        columns[i],
+        "Se esperaba cierre de la interpolacion",
+      ));
     }
-    let nc = nc.unwrap();
-    if nc == '{' {
-      current.push('{');
-      continue;
+    let expr_end = format_index.unwrap_or(end);
+    let fragment = string[start..expr_end].iter().collect::<String>();
+    if fragment.trim().is_empty() {
+      return Err(string_error(
+        &token_string,
+        line,
+        columns[i],
+        "Se esperaba una expresion en la interpolacion",
+      ));
     }
-    if nc == '}' {}
-    is_id = true;
-    result.push(StringData::Str(current.clone()));
-    current.clear();
-    current.push(nc);
-  }
-  if is_id {
-    return Err(NodeError {
-      message: "Se esperaba cierre del identificador".to_string(),
-      location: token_string.location,
-      meta: format!("{}\0{}", line, string),
-    });
+    let format = format_index.map(|index| string[index + 1..end].iter().collect::<String>());
+    let node =
+      parser.parse_fragment(&fragment, token_string.location.start.line, columns[start])?;
+    if !current.is_empty() {
+      result.push(StringData::Str(current.clone()));
+      current.clear();
+    }
+    result.push(StringData::Expr(node.to_box(), format));
+    i = end + 1;
   }
   if current.len() > 0 {
     result.push(StringData::Str(current));
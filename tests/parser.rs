@@ -0,0 +1,152 @@
+use parser::{ast, Parser};
+
+fn parse(source: &str, top_level_await: bool) -> Result<ast::Node, ast::NodeError> {
+  let mut parser = Parser::new(source.to_string(), "prueba.agal");
+  parser.set_top_level_await(top_level_await);
+  parser.produce_ast()
+}
+
+#[test]
+fn espera_in_async_function() {
+  assert!(parse("asinc fn f() { def x = espera g(); }", false).is_ok());
+}
+
+#[test]
+fn espera_in_async_method() {
+  assert!(parse("clase A { asinc m() { espera g(); } }", false).is_ok());
+}
+
+#[test]
+fn espera_in_nested_function() {
+  assert!(parse("asinc fn f() { fn h() { espera g(); } }", false).is_err());
+}
+
+#[test]
+fn espera_in_field_initializer() {
+  let source = "asinc fn f() { clase A { x = espera g(); } }";
+  assert!(parse(source, false).is_err());
+  assert!(parse(source, true).is_err());
+}
+
+#[test]
+fn espera_at_top_level() {
+  assert!(parse("espera g();", true).is_ok());
+  assert!(parse("espera g();", false).is_err());
+}
+
+fn first_stmt(source: &str) -> ast::Node {
+  match parse(source, true) {
+    Ok(ast::Node::Program(program)) => program.body.body.into_iter().next().unwrap(),
+    _ => panic!("no se pudo analizar: {}", source),
+  }
+}
+
+fn binary(node: &ast::Node) -> &ast::NodeBinary {
+  match node {
+    ast::Node::Binary(binary) => binary,
+    _ => panic!("se esperaba una operacion binaria: {}", node),
+  }
+}
+
+#[test]
+fn es_en_bind_tighter_than_logical_operators() {
+  let node = first_stmt("x es Clase && k en obj;");
+  let and = binary(&node);
+  assert_eq!(and.operator, ast::NodeOperator::And);
+  let is = binary(&and.left);
+  assert_eq!(is.operator, ast::NodeOperator::Is);
+  assert!(matches!(&*is.left, ast::Node::Identifier(id) if id.name == "x"));
+  assert!(matches!(&*is.right, ast::Node::Identifier(id) if id.name == "Clase"));
+  let membership = binary(&and.right);
+  assert_eq!(membership.operator, ast::NodeOperator::In);
+  assert!(matches!(&*membership.left, ast::Node::Identifier(id) if id.name == "k"));
+  assert!(matches!(&*membership.right, ast::Node::Identifier(id) if id.name == "obj"));
+}
+
+#[test]
+fn tipo_is_an_identifier_without_de() {
+  assert!(parse("def tipo = 1; obj.tipo = tipo; def o = {tipo: 2};", true).is_ok());
+  let node = first_stmt("tipo de x;");
+  assert!(
+    matches!(node, ast::Node::UnaryFront(unary) if unary.operator == ast::NodeOperator::TypeOf)
+  );
+}
+
+#[test]
+fn operador_as_a_member_name() {
+  assert!(parse("clase A { operador = 1; }", true).is_ok());
+  assert!(parse("clase A { operador = (1 + 2); }", true).is_ok());
+  assert!(parse("clase A { operador() { ret 1; } }", true).is_ok());
+  assert!(parse("clase A { operador + (otro) { ret 1; } }", true).is_ok());
+}
+
+#[test]
+fn difiere_in_any_block() {
+  assert!(parse("difiere cerrar();", true).is_ok());
+  assert!(parse("si a { difiere cerrar(); }", true).is_ok());
+  assert!(parse("fn f() { difiere cerrar(); }", true).is_ok());
+}
+
+fn is_identifier(node: &ast::Node, name: &str) -> bool {
+  matches!(node, ast::Node::Identifier(id) if id.name == name)
+}
+
+#[test]
+fn pipeline_in_compound_assignment() {
+  let node = first_stmt("x += a |> f;");
+  let assignment = match &node {
+    ast::Node::Assignment(assignment) => assignment,
+    _ => panic!("se esperaba una asignacion: {}", node),
+  };
+  assert!(is_identifier(&assignment.identifier, "x"));
+  let plus = binary(&assignment.value);
+  assert_eq!(plus.operator, ast::NodeOperator::Plus);
+  assert!(is_identifier(&plus.left, "x"));
+  assert!(matches!(&*plus.right, ast::Node::Pipeline(pipeline)
+    if is_identifier(&pipeline.value, "a") && is_identifier(&pipeline.stage, "f")));
+}
+
+#[test]
+fn pipeline_stage_is_not_an_assignment() {
+  assert!(parse("x |> f = 3;", true).is_err());
+  assert!(parse("a |> b += 1;", true).is_err());
+}
+
+#[test]
+fn pipeline_is_left_associative() {
+  let node = first_stmt("x |> a |> b(2);");
+  let outer = match &node {
+    ast::Node::Pipeline(pipeline) => pipeline,
+    _ => panic!("se esperaba una tuberia: {}", node),
+  };
+  assert!(matches!(&*outer.value, ast::Node::Pipeline(inner)
+    if is_identifier(&inner.value, "x") && is_identifier(&inner.stage, "a")));
+  assert!(matches!(&*outer.stage, ast::Node::Call(_)));
+}
+
+/// column and text underlined by the error of an invalid assignment
+fn underlined(source: &str) -> (usize, String) {
+  let error = parse(source, true).expect_err(source);
+  let text = error.meta.split('\0').nth(1).unwrap_or("").to_string();
+  (error.location.start.column, text)
+}
+
+#[test]
+fn invalid_assignment_targets_are_underlined() {
+  assert_eq!(underlined("3 = x;"), (0, "3".to_string()));
+  assert_eq!(underlined("f() += 1;"), (0, "f()".to_string()));
+  assert_eq!(underlined("(a + b) = c;"), (0, "(a + b)".to_string()));
+  assert_eq!(underlined("const a = 1; a = 2;"), (13, "a".to_string()));
+}
+
+#[test]
+fn importa_inside_nombre() {
+  assert!(parse("nombre X { nombre Y { def a = 1; } fn f() {} }", true).is_ok());
+  assert!(parse("nombre X { importa 'a' como b; }", true).is_err());
+}
+
+#[test]
+fn interfaz_signature_without_semicolon() {
+  let error = parse("interfaz I { m() }", true).unwrap_err();
+  assert_eq!(error.message, "Se esperaba ';' o un cuerpo");
+}